@@ -8,7 +8,7 @@ use std::error;
 #[cfg(feature = "toml")]
 fn main() -> Result<(), Box<dyn error::Error>> {
     let mastodon = register::get_mastodon_data()?;
-    let tl = mastodon.get_home_timeline()?;
+    let tl = mastodon.get_home_timeline(None)?;
 
     println!("{:#?}", tl);
 
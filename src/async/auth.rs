@@ -1,18 +1,6 @@
 //! Authentication mechanisms for async client
-use async_mutex::Mutex;
-use std::cell::RefCell;
-
-use crate::{
-    entities::{account::Account, card::Card, context::Context, status::Status},
-    errors::{Error, Result},
-    requests::StatusesRequest,
-};
-use http_types::{Method, Request, Response};
-use hyper_old_types::header::{parsing, Link, RelationType};
-use serde::Serialize;
-use smol::{prelude::*, Async};
-use std::net::{TcpStream, ToSocketAddrs};
-use url::Url;
+use crate::{data::Data, errors::Result, scopes::Scopes};
+use http_types::Request;
 
 /// strategies for authenticating mastodon requests need to implement this trait
 #[async_trait::async_trait]
@@ -30,17 +18,90 @@ impl Authenticate for Unauthenticated {
     }
 }
 
-/// Authenticates to the server via oauth
+/// Authenticates to the server via oauth, sending the access token as a
+/// bearer token with every request
 #[derive(Debug, Clone, PartialEq)]
 pub struct OAuth {
-    client_id: String,
-    client_secret: String,
-    redirect: String,
-    token: String,
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    pub(crate) redirect: String,
+    pub(crate) token: String,
+    pub(crate) scopes: Option<Scopes>,
 }
+
+impl OAuth {
+    /// Authenticate with an access token that was obtained elsewhere, without
+    /// the app credentials it was issued to
+    pub fn from_token<S: Into<String>>(token: S) -> OAuth {
+        OAuth {
+            client_id: String::new(),
+            client_secret: String::new(),
+            redirect: String::new(),
+            token: token.into(),
+            scopes: None,
+        }
+    }
+
+    /// The access token used to authenticate requests
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The scopes granted to the access token, if known
+    pub fn scopes(&self) -> Option<&Scopes> {
+        self.scopes.as_ref()
+    }
+}
+
+impl From<Data> for OAuth {
+    fn from(data: Data) -> OAuth {
+        OAuth {
+            client_id: data.client_id.into_owned(),
+            client_secret: data.client_secret.into_owned(),
+            redirect: data.redirect.into_owned(),
+            token: data.token.into_owned(),
+            scopes: data.scopes,
+        }
+    }
+}
+
 #[async_trait::async_trait]
-impl Authenticate for Mutex<RefCell<Option<OAuth>>> {
-    async fn authenticate(&self, _: &mut Request) -> Result<()> {
-        unimplemented!()
+impl Authenticate for OAuth {
+    async fn authenticate(&self, request: &mut Request) -> Result<()> {
+        request.insert_header("Authorization", format!("Bearer {}", self.token));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_types::Method;
+
+    #[test]
+    fn test_oauth_sets_bearer_token() {
+        let auth = OAuth::from_token("sometoken");
+        let mut request = Request::new(
+            Method::Get,
+            url::Url::parse("https://example.com/api/v1/accounts/verify_credentials")
+                .expect("Couldn't parse url"),
+        );
+        smol::block_on(auth.authenticate(&mut request)).expect("Couldn't authenticate");
+        assert_eq!(
+            request
+                .header("Authorization")
+                .map(|values| values.as_str()),
+            Some("Bearer sometoken")
+        );
+    }
+
+    #[test]
+    fn test_unauthenticated_sets_nothing() {
+        let mut request = Request::new(
+            Method::Get,
+            url::Url::parse("https://example.com/api/v1/instance").expect("Couldn't parse url"),
+        );
+        smol::block_on(Unauthenticated.authenticate(&mut request)).expect("Couldn't authenticate");
+        assert!(request.header("Authorization").is_none());
     }
 }
@@ -27,6 +27,7 @@
 #![allow(warnings)]
 #![allow(missing_docs)]
 use crate::{
+    data::Data,
     entities::{
         account::Account,
         activity::Activity,
@@ -37,19 +38,20 @@ use crate::{
         status::{Emoji, Status, Tag},
     },
     errors::{Error, Result},
-    requests::{DirectoryRequest, StatusesRequest},
+    requests::{DirectoryRequest, StatusesRequest, TimelineRequest},
 };
 use http_types::{Method, Request, Response};
 use std::fmt::Debug;
 use url::Url;
 
-pub use auth::Authenticate;
-use auth::{OAuth, Unauthenticated};
+pub use auth::{Authenticate, OAuth, Unauthenticated};
 pub use page::Page;
+pub use registration::{Registered, Registration};
 
 mod auth;
 mod client;
 mod page;
+mod registration;
 
 /// Async unauthenticated client
 #[derive(Debug)]
@@ -66,38 +68,78 @@ impl Client<Unauthenticated> {
         })
     }
 }
+impl Client<OAuth> {
+    /// Create a client that authenticates with an access token that was
+    /// obtained elsewhere
+    pub fn with_token<S: AsRef<str>, T: Into<String>>(
+        base_url: S,
+        token: T,
+    ) -> Result<Client<OAuth>> {
+        let base_url = Url::parse(base_url.as_ref())?;
+        Ok(Client {
+            base_url,
+            auth: OAuth::from_token(token),
+        })
+    }
+
+    /// Create a client from data saved after a previous registration
+    pub fn from_data(data: Data) -> Result<Client<OAuth>> {
+        let base_url = Url::parse(&data.base)?;
+        Ok(Client {
+            base_url,
+            auth: OAuth::from(data),
+        })
+    }
+
+    /// GET /api/v1/accounts/verify_credentials
+    pub async fn verify_credentials(&self) -> Result<Account> {
+        let url = self.base_url.join("api/v1/accounts/verify_credentials")?;
+        let response = self.send(Request::new(Method::Get, url)).await?;
+        Ok(deserialize(response).await?)
+    }
+
+    /// GET /api/v1/timelines/home
+    pub async fn home_timeline<'a, 'client: 'a, I: Into<Option<TimelineRequest<'a>>>>(
+        &'client self,
+        opts: I,
+    ) -> Result<Page<'client, Status, OAuth>> {
+        let url = self.timeline_url("api/v1/timelines/home", opts.into())?;
+        Ok(Page::new(Request::new(Method::Get, url), &self.auth))
+    }
+}
+
 impl<A: Debug + Authenticate> Client<A> {
     async fn send(&self, mut req: Request) -> Result<Response> {
         self.auth.authenticate(&mut req).await?;
         Ok(client::fetch(req).await?)
     }
 
+    fn timeline_url(&self, path: &str, opts: Option<TimelineRequest>) -> Result<Url> {
+        let mut url = self.base_url.join(path)?;
+        if let Some(opts) = opts {
+            let qs = opts.to_querystring()?;
+            // `to_querystring` includes the leading `?`
+            url.set_query(Some(qs.trim_start_matches('?')));
+        }
+        Ok(url)
+    }
+
     /// GET /api/v1/timelines/public
-    pub async fn public_timeline<'a, 'client: 'a, I: Into<Option<StatusesRequest<'a>>>>(
+    pub async fn public_timeline<'a, 'client: 'a, I: Into<Option<TimelineRequest<'a>>>>(
         &'client self,
         opts: I,
     ) -> Result<Page<'client, Status, A>> {
-        let mut url = self.base_url.join("api/v1/timelines/public")?;
-        if let Some(opts) = opts.into() {
-            let qs = opts.to_querystring()?;
-            url.set_query(Some(&qs[..]));
-        };
+        let url = self.timeline_url("api/v1/timelines/public", opts.into())?;
         Ok(Page::new(Request::new(Method::Get, url), &self.auth))
     }
 
     /// GET /api/v1/timelines/tag/:tag
-    pub async fn hashtag_timeline<'a, 'client: 'a, I: Into<Option<StatusesRequest<'a>>>>(
+    pub async fn hashtag_timeline<'a, 'client: 'a, I: Into<Option<TimelineRequest<'a>>>>(
         &'client self,
         tag: &str,
         opts: I,
     ) -> Result<Page<'client, Status, A>> {
-        let mut url = self
-            .base_url
-            .join(&format!("api/v1/timelines/tag/{}", tag))?;
-        if let Some(opts) = opts.into() {
-            let qs = opts.to_querystring()?;
-            url.set_query(Some(&qs[..]));
-        }
+        let url = self.timeline_url(&format!("api/v1/timelines/tag/{}", tag), opts.into())?;
         Ok(Page::new(Request::new(Method::Get, url), &self.auth))
     }
 
@@ -261,3 +303,62 @@ async fn deserialize<T: serde::de::DeserializeOwned>(mut response: Response) ->
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_timeline_url() {
+        let client = Client::new("https://example.com").expect("Couldn't create client");
+        let url = client
+            .timeline_url(
+                "api/v1/timelines/public",
+                Some(TimelineRequest::new().local().only_media()),
+            )
+            .expect("Couldn't build url");
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/api/v1/timelines/public?local=1&only_media=1"
+        );
+    }
+
+    #[test]
+    fn test_home_timeline_url() {
+        let client =
+            Client::with_token("https://example.com", "sometoken").expect("Couldn't create client");
+        let url = client
+            .timeline_url(
+                "api/v1/timelines/home",
+                Some(TimelineRequest::new().max_id("10").limit(20)),
+            )
+            .expect("Couldn't build url");
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/api/v1/timelines/home?max_id=10&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_hashtag_timeline_url() {
+        let client = Client::new("https://example.com").expect("Couldn't create client");
+        let url = client
+            .timeline_url(
+                "api/v1/timelines/tag/cats",
+                Some(TimelineRequest::new().any_tag("dogs").limit(5)),
+            )
+            .expect("Couldn't build url");
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/api/v1/timelines/tag/cats?limit=5&any%5B%5D=dogs"
+        );
+
+        let url = client
+            .timeline_url("api/v1/timelines/tag/cats", None)
+            .expect("Couldn't build url");
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/api/v1/timelines/tag/cats"
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! Registering an app and authenticating a user with the async client
+use std::{borrow::Cow, convert::TryInto};
+
+use http_types::{Body, Method, Request};
+use url::Url;
+
+use super::{client, deserialize, Client, OAuth};
+use crate::{
+    apps::{App, AppBuilder},
+    registration::{
+        code_challenge,
+        generate_code_verifier,
+        generate_state,
+        parse_token_response,
+        verify_state,
+        AccessToken,
+        OAuth as AppCredentials,
+    },
+    scopes::Scopes,
+    Error,
+    Result,
+};
+
+/// Handles registering your mastodon app to your instance with the async
+/// client. It is recommended you cache the resulting `Data` to avoid
+/// registering on every run.
+#[derive(Debug, Clone)]
+pub struct Registration<'a> {
+    base: String,
+    app_builder: AppBuilder<'a>,
+    force_login: bool,
+    pkce: bool,
+}
+
+impl<'a> Registration<'a> {
+    /// Construct a new registration process to the instance of the `base` url.
+    /// ```
+    /// use elefren::r#async::Registration;
+    ///
+    /// let registration = Registration::new("https://mastodon.social");
+    /// ```
+    pub fn new<I: Into<String>>(base: I) -> Self {
+        Registration {
+            base: base.into(),
+            app_builder: AppBuilder::new(),
+            force_login: false,
+            pkce: true,
+        }
+    }
+
+    /// Sets the name of this app
+    ///
+    /// This is required, and if this isn't set then the AppBuilder::build
+    /// method will fail
+    pub fn client_name<I: Into<Cow<'a, str>>>(&mut self, name: I) -> &mut Self {
+        self.app_builder.client_name(name.into());
+        self
+    }
+
+    /// Sets the redirect uris that this app uses
+    pub fn redirect_uris<I: Into<Cow<'a, str>>>(&mut self, uris: I) -> &mut Self {
+        self.app_builder.redirect_uris(uris);
+        self
+    }
+
+    /// Sets the scopes that this app requires
+    ///
+    /// The default for an app is Scopes::Read
+    pub fn scopes(&mut self, scopes: Scopes) -> &mut Self {
+        self.app_builder.scopes(scopes);
+        self
+    }
+
+    /// Sets the optional "website" to register the app with
+    pub fn website<I: Into<Cow<'a, str>>>(&mut self, website: I) -> &mut Self {
+        self.app_builder.website(website);
+        self
+    }
+
+    /// Forces the user to re-login (useful if you need to re-auth as a
+    /// different user on the same instance
+    pub fn force_login(&mut self, force_login: bool) -> &mut Self {
+        self.force_login = force_login;
+        self
+    }
+
+    /// Sets whether to use PKCE, sending a `code_challenge` to the
+    /// authorization page and the matching `code_verifier` when completing
+    /// the flow. This is on by default.
+    pub fn pkce(&mut self, pkce: bool) -> &mut Self {
+        self.pkce = pkce;
+        self
+    }
+
+    /// Register the given application
+    ///
+    /// ```no_run
+    /// # fn main() -> elefren::Result<()> {
+    /// #   smol::block_on(async {
+    /// use elefren::{apps::App, r#async::Registration};
+    ///
+    /// let mut app = App::builder();
+    /// app.client_name("elefren_test");
+    ///
+    /// let registration = Registration::new("https://mastodon.social")
+    ///     .register(app)
+    ///     .await?;
+    /// let url = registration.authorize_url()?;
+    /// // Here you now need to open the url in the browser
+    /// // And handle a the redirect url coming back with the code.
+    /// let code = String::from("RETURNED_FROM_BROWSER");
+    /// let client = registration.complete(&code).await?;
+    ///
+    /// println!("{:?}", client.verify_credentials().await?);
+    /// #   Ok(())
+    /// #   })
+    /// # }
+    /// ```
+    pub async fn register<I: TryInto<App>>(&mut self, app: I) -> Result<Registered>
+    where
+        Error: From<<I as TryInto<App>>::Error>,
+    {
+        let app = app.try_into()?;
+        self.send_app(app).await
+    }
+
+    /// Register the application with the server from the `base` url.
+    ///
+    /// ```no_run
+    /// # fn main() -> elefren::Result<()> {
+    /// #   smol::block_on(async {
+    /// use elefren::r#async::Registration;
+    ///
+    /// let registration = Registration::new("https://mastodon.social")
+    ///     .client_name("elefren_test")
+    ///     .build()
+    ///     .await?;
+    /// let url = registration.authorize_url()?;
+    /// // Here you now need to open the url in the browser
+    /// // And handle a the redirect url coming back with the code.
+    /// let code = String::from("RETURNED_FROM_BROWSER");
+    /// let client = registration.complete(&code).await?;
+    ///
+    /// println!("{:?}", client.verify_credentials().await?);
+    /// #   Ok(())
+    /// #   })
+    /// # }
+    /// ```
+    pub async fn build(&mut self) -> Result<Registered> {
+        let app: App = self.app_builder.clone().build()?;
+        self.send_app(app).await
+    }
+
+    async fn send_app(&self, app: App) -> Result<Registered> {
+        let url = route(&self.base, "/api/v1/apps")?;
+        let mut request = Request::new(Method::Post, url);
+        request.set_body(Body::from_json(&app)?);
+        let oauth: AppCredentials = deserialize(client::fetch(request).await?).await?;
+
+        Ok(Registered {
+            base: self.base.clone(),
+            client_id: oauth.client_id,
+            client_secret: oauth.client_secret,
+            redirect: oauth.redirect_uri,
+            scopes: app.scopes().clone(),
+            force_login: self.force_login,
+            state: generate_state(),
+            code_verifier: if self.pkce {
+                Some(generate_code_verifier())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+// Appended rather than joined, so that a path prefix on `base` is kept, like
+// the blocking client does.
+fn route(base: &str, path: &str) -> Result<Url> {
+    Ok(Url::parse(&format!("{}{}", base, path))?)
+}
+
+/// Represents the state of the async auth flow when the app has been
+/// registered but the user is not authenticated
+#[derive(Debug, Clone)]
+pub struct Registered {
+    base: String,
+    client_id: String,
+    client_secret: String,
+    redirect: String,
+    scopes: Scopes,
+    force_login: bool,
+    state: String,
+    code_verifier: Option<String>,
+}
+
+impl Registered {
+    /// Skip having to retrieve the client id and secret from the server by
+    /// creating a `Registered` struct directly
+    ///
+    /// The parts don't include the `state` and PKCE code verifier, so PKCE is
+    /// off for the result and a new `state` is generated. Use `with_state`
+    /// and `with_code_verifier` to restore a flow in progress.
+    pub fn from_parts(
+        base: &str,
+        client_id: &str,
+        client_secret: &str,
+        redirect: &str,
+        scopes: Scopes,
+        force_login: bool,
+    ) -> Registered {
+        Registered {
+            base: base.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect: redirect.to_string(),
+            scopes,
+            force_login,
+            state: generate_state(),
+            code_verifier: None,
+        }
+    }
+
+    /// Returns the parts of the `Registered` struct that can be used to
+    /// recreate another `Registered` struct
+    ///
+    /// The `state` and PKCE code verifier are not included, save them from
+    /// `state` and `code_verifier` first if the flow is still in progress.
+    pub fn into_parts(self) -> (String, String, String, String, Scopes, bool) {
+        (
+            self.base,
+            self.client_id,
+            self.client_secret,
+            self.redirect,
+            self.scopes,
+            self.force_login,
+        )
+    }
+
+    /// The `state` parameter sent to the authorization page
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// Use the given `state` parameter instead of the generated one
+    pub fn with_state<S: Into<String>>(mut self, state: S) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    /// The PKCE code verifier sent when completing the flow, if PKCE is used
+    pub fn code_verifier(&self) -> Option<&str> {
+        self.code_verifier.as_deref()
+    }
+
+    /// Use the given PKCE code verifier instead of the generated one, or turn
+    /// PKCE off with `None`
+    pub fn with_code_verifier(mut self, code_verifier: Option<String>) -> Self {
+        self.code_verifier = code_verifier;
+        self
+    }
+
+    /// Checks the `state` parameter that the authorization page redirected
+    /// back with against the one that was sent
+    pub fn verify_state(&self, state: &str) -> Result<()> {
+        verify_state(&self.state, state)
+    }
+
+    /// Returns the full url needed for authorisation. This needs to be opened
+    /// in a browser.
+    pub fn authorize_url(&self) -> Result<String> {
+        let mut url = route(&self.base, "/oauth/authorize")?;
+
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect)
+            .append_pair("scope", &self.scopes.to_string())
+            .append_pair("response_type", "code")
+            .append_pair("force_login", &self.force_login.to_string())
+            .append_pair("state", &self.state);
+        if let Some(ref code_verifier) = self.code_verifier {
+            url.query_pairs_mut()
+                .append_pair("code_challenge", &code_challenge(code_verifier))
+                .append_pair("code_challenge_method", "S256");
+        }
+
+        Ok(url.into())
+    }
+
+    /// Create an access token from the client id, client secret, and code
+    /// provided by the authorisation url.
+    pub async fn complete(&self, code: &str) -> Result<Client<OAuth>> {
+        let base_url = Url::parse(&self.base)?;
+        let token = self.access_token(code).await?;
+
+        Ok(Client {
+            base_url,
+            auth: OAuth {
+                client_id: self.client_id.clone(),
+                client_secret: self.client_secret.clone(),
+                redirect: self.redirect.clone(),
+                scopes: token.granted_scopes(&self.scopes),
+                token: token.access_token,
+            },
+        })
+    }
+
+    /// Exchange the code provided by the authorisation url for an access
+    /// token, without creating a client from it.
+    pub async fn access_token(&self, code: &str) -> Result<AccessToken> {
+        let url = route(&self.base, "/oauth/token")?;
+        let mut form = vec![
+            ("client_id", &self.client_id[..]),
+            ("client_secret", &self.client_secret[..]),
+            ("code", code),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", &self.redirect[..]),
+        ];
+        if let Some(ref code_verifier) = self.code_verifier {
+            form.push(("code_verifier", code_verifier));
+        }
+        let mut request = Request::new(Method::Post, url);
+        request.set_body(Body::from_form(&form)?);
+        let mut response = client::fetch(request).await?;
+        let status = reqwest::StatusCode::from_u16(response.status().into())
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let body = response.body_bytes().await?;
+        parse_token_response(status, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_new() {
+        let r = Registration::new("https://example.com");
+        assert_eq!(r.base, "https://example.com".to_string());
+        assert_eq!(r.app_builder, AppBuilder::new());
+        assert!(!r.force_login);
+    }
+
+    #[test]
+    fn test_route_keeps_path_prefix() {
+        assert_eq!(
+            route("https://example.com/mastodon", "/oauth/token")
+                .expect("Couldn't build url")
+                .as_str(),
+            "https://example.com/mastodon/oauth/token"
+        );
+        assert_eq!(
+            route("https://example.com", "/api/v1/apps")
+                .expect("Couldn't build url")
+                .as_str(),
+            "https://example.com/api/v1/apps"
+        );
+    }
+
+    #[test]
+    fn test_parts_round_trip() {
+        let registered = Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            "urn:ietf:wg:oauth:2.0:oob",
+            Scopes::read_all(),
+            false,
+        )
+        .with_code_verifier(Some(generate_code_verifier()));
+        let url = registered.authorize_url().expect("Couldn't build url");
+        let state = registered.state().to_string();
+        let code_verifier = registered.code_verifier().map(String::from);
+
+        let (base, client_id, client_secret, redirect, scopes, force_login) =
+            registered.into_parts();
+        let restored = Registered::from_parts(
+            &base,
+            &client_id,
+            &client_secret,
+            &redirect,
+            scopes,
+            force_login,
+        )
+        .with_state(state)
+        .with_code_verifier(code_verifier.clone());
+
+        assert_eq!(restored.authorize_url().expect("Couldn't build url"), url);
+        assert_eq!(restored.code_verifier().map(String::from), code_verifier);
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let registered = Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            "urn:ietf:wg:oauth:2.0:oob",
+            Scopes::read_all(),
+            true,
+        )
+        .with_state("the-state")
+        .with_code_verifier(None);
+        assert_eq!(
+            registered.authorize_url().expect("Couldn't build url"),
+            "https://example.com/oauth/authorize?client_id=the-client-id&redirect_uri=urn%3Aietf%\
+             3Awg%3Aoauth%3A2.0%3Aoob&scope=read&response_type=code&force_login=true&state=the-\
+             state"
+        );
+    }
+
+    #[test]
+    fn test_authorize_url_keeps_path_prefix() {
+        let registered = Registered::from_parts(
+            "https://example.com/mastodon",
+            "the-client-id",
+            "the-client-secret",
+            "urn:ietf:wg:oauth:2.0:oob",
+            Scopes::read_all(),
+            false,
+        );
+        assert!(registered
+            .authorize_url()
+            .expect("Couldn't build url")
+            .starts_with("https://example.com/mastodon/oauth/authorize?"));
+    }
+}
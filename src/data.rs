@@ -1,3 +1,4 @@
+use crate::scopes::Scopes;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
@@ -15,4 +16,7 @@ pub struct Data {
     pub redirect: Cow<'static, str>,
     /// The client's access token.
     pub token: Cow<'static, str>,
+    /// The scopes granted to the access token, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Scopes>,
 }
@@ -0,0 +1,171 @@
+//! Module containing everything related to the admin API.
+use super::account::Account;
+use chrono::prelude::*;
+use serde::Deserialize;
+
+/// Admin-level information about a given account.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AdminAccount {
+    /// The ID of the account in the database.
+    pub id: String,
+    /// The username of the account.
+    pub username: String,
+    /// The domain of the account, `None` for local accounts.
+    pub domain: Option<String>,
+    /// When the account was first discovered.
+    pub created_at: DateTime<Utc>,
+    /// The email address associated with the account.
+    pub email: Option<String>,
+    /// The IP address last used to login to this account.
+    pub ip: Option<String>,
+    /// All known IP addresses associated with this account.
+    #[serde(default)]
+    pub ips: Vec<AdminIp>,
+    /// The locale of the account.
+    pub locale: Option<String>,
+    /// The reason given when requesting an invite (for instances that require
+    /// manual approval of registrations).
+    pub invite_request: Option<String>,
+    /// The current role of the account.
+    pub role: Option<AdminRole>,
+    /// Whether the account has confirmed their email address.
+    pub confirmed: bool,
+    /// Whether the account is currently suspended.
+    pub suspended: bool,
+    /// Whether the account is currently silenced.
+    pub silenced: bool,
+    /// Whether the account is currently disabled.
+    pub disabled: bool,
+    /// Whether the account is currently approved.
+    pub approved: bool,
+    /// Whether the account's media is forced to be marked as sensitive.
+    pub sensitized: Option<bool>,
+    /// User-level information about the account.
+    pub account: Account,
+    /// The ID of the application that created this account, if applicable.
+    pub created_by_application_id: Option<String>,
+    /// The ID of the account that invited this user, if applicable.
+    pub invited_by_account_id: Option<String>,
+}
+
+/// An IP address used by an account, and when it was last used.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AdminIp {
+    /// The IP address.
+    pub ip: String,
+    /// The timestamp of when the IP address was last used for this account.
+    pub used_at: DateTime<Utc>,
+}
+
+/// The role of an account.
+///
+/// Older servers send the role as a plain string (`user`, `moderator` or
+/// `admin`), newer ones send a full role object.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum AdminRole {
+    /// A role object, as sent by Mastodon 4.0 and later.
+    Role(Role),
+    /// The name of the role, as sent by older servers.
+    Legacy(String),
+}
+
+/// A role that can be assigned to an account.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Role {
+    /// The ID of the role in the database.
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub id: u64,
+    /// The name of the role.
+    pub name: String,
+    /// The hex code assigned to this role, may be empty.
+    pub color: String,
+    /// A bitmask of the permissions granted by this role.
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub permissions: u64,
+    /// Whether the role is publicly visible as a badge on user profiles.
+    pub highlighted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_admin_account() {
+        let json = r##"{
+            "id": "108965278956942133",
+            "username": "admin",
+            "domain": null,
+            "created_at": "2022-09-08T23:03:26.762Z",
+            "email": "admin@mastodon.local",
+            "ip": "192.168.42.1",
+            "ips": [
+                {
+                    "ip": "192.168.42.1",
+                    "used_at": "2022-09-15T01:38:58.851Z"
+                }
+            ],
+            "locale": null,
+            "invite_request": null,
+            "role": {
+                "id": 3,
+                "name": "Owner",
+                "color": "",
+                "position": 1000,
+                "permissions": 1,
+                "highlighted": true,
+                "created_at": "2022-09-08T22:48:07.983Z",
+                "updated_at": "2022-09-08T22:48:07.983Z"
+            },
+            "confirmed": true,
+            "suspended": false,
+            "silenced": false,
+            "sensitized": false,
+            "disabled": false,
+            "approved": true,
+            "account": {
+                "id": "108965278956942133",
+                "username": "admin",
+                "acct": "admin",
+                "display_name": "",
+                "locked": false,
+                "bot": false,
+                "created_at": "2022-09-08T00:00:00.000Z",
+                "note": "",
+                "url": "http://mastodon.local/@admin",
+                "avatar": "http://mastodon.local/avatars/original/missing.png",
+                "avatar_static": "http://mastodon.local/avatars/original/missing.png",
+                "header": "http://mastodon.local/headers/original/missing.png",
+                "header_static": "http://mastodon.local/headers/original/missing.png",
+                "followers_count": 0,
+                "following_count": 0,
+                "statuses_count": 0,
+                "emojis": [],
+                "fields": []
+            },
+            "created_by_application_id": null,
+            "invited_by_account_id": null
+        }"##;
+        let account: AdminAccount =
+            serde_json::from_str(json).expect("Couldn't deserialize admin account");
+        assert_eq!(account.id, "108965278956942133");
+        assert_eq!(account.ips.len(), 1);
+        assert!(account.approved);
+        match account.role {
+            Some(AdminRole::Role(role)) => {
+                assert_eq!(role.name, "Owner");
+                assert_eq!(role.permissions, 1);
+            },
+            other => panic!("expected a role object, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_legacy_role() {
+        let role: AdminRole =
+            serde_json::from_str(r#""moderator""#).expect("Couldn't deserialize role");
+        assert_eq!(role, AdminRole::Legacy("moderator".to_string()));
+    }
+}
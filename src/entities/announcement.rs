@@ -1,3 +1,6 @@
+//! Module containing everything related to announcements.
+use super::status::{Emoji, Mention, Tag};
+use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Custom emoji fields for AnnouncementReaction
@@ -12,39 +15,101 @@ pub struct AnnouncementReactionCustomEmoji {
 /// Represents an emoji reaction to an Announcement.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct AnnouncementReaction {
-    /// The emoji used for the reaction. Either a unicode emoji, or a custom emoji's shortcode.
+    /// The emoji used for the reaction. Either a unicode emoji, or a custom
+    /// emoji's shortcode.
     pub name: String,
     /// The total number of users who have added this reaction.
     pub count: u64,
     /// Whether the authorized user has added this reaction to the announcement.
-    pub me: bool,
+    pub me: Option<bool>,
+    /// The custom emoji URLs, if the reaction is a custom emoji.
     #[serde(flatten)]
     pub emoji: Option<AnnouncementReactionCustomEmoji>,
 }
 
-/// Represents an announcement set by an administrator.
+/// A status linked to from an Announcement.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AnnouncementStatus {
+    /// The ID of an attached Status in the database.
+    pub id: String,
+    /// The URL of an attached Status.
+    pub url: String,
+}
+
+/// Represents an announcement set by an administrator.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Announcement {
     /// The announcement id.
-    id: String,
-    /// The content of the announcement.
-    text: String,
-    /// Whether the announcement is currently active.
-    published: bool,
-    /// Whether the announcement has a start/end time.
-    all_day: bool,
-    /// When the announcement was created.
-    created_at: String, // Datetime
+    pub id: String,
+    /// The HTML content of the announcement.
+    pub content: String,
+    /// When the announcement will start.
+    pub starts_at: Option<DateTime<Utc>>,
+    /// When the announcement will end.
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Whether the announcement should start and end on dates only instead of
+    /// datetimes.
+    pub all_day: bool,
+    /// When the announcement was published.
+    pub published_at: DateTime<Utc>,
     /// When the announcement was last updated.
-    updated_at: String, // Datetime
+    pub updated_at: DateTime<Utc>,
     /// Whether the announcement has been read by the user.
-    read: bool,
+    pub read: Option<bool>,
+    /// Accounts mentioned in the announcement text.
+    pub mentions: Vec<Mention>,
+    /// Statuses linked in the announcement text.
+    pub statuses: Vec<AnnouncementStatus>,
+    /// Tags linked in the announcement text.
+    pub tags: Vec<Tag>,
+    /// Custom emoji used in the announcement text.
+    pub emojis: Vec<Emoji>,
     /// Emoji reactions attached to the announcement.
-    reactions: Vec<AnnouncementReaction>,
-    /// When the future announcement was scheduled.
-    scheduled_at: Option<String>, // Datetime
-    /// When the future announcement will start.
-    starts_at: Option<String>, // Datetime
-    /// When the future announcement will end.
-    ends_at: Option<String>, // Datetime
-}
\ No newline at end of file
+    pub reactions: Vec<AnnouncementReaction>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_announcement() {
+        let json = r#"{
+            "id": "8",
+            "content": "<p>Looks like there was an issue processing audio attachments without embedded art since yesterday due to an experimental new feature. That issue has now been fixed, so you may see older posts with audio from other servers pop up in your feeds now as they are being finally properly processed. Sorry!</p>",
+            "starts_at": null,
+            "ends_at": null,
+            "all_day": false,
+            "published_at": "2020-07-03T01:27:38.726Z",
+            "updated_at": "2020-07-03T01:27:38.752Z",
+            "read": true,
+            "mentions": [],
+            "statuses": [],
+            "tags": [],
+            "emojis": [],
+            "reactions": [
+                {
+                    "name": "bongoCat",
+                    "count": 9,
+                    "me": false,
+                    "url": "https://files.mastodon.social/custom_emojis/images/000/067/715/original/fdba57dff7576d53.png",
+                    "static_url": "https://files.mastodon.social/custom_emojis/images/000/067/715/static/fdba57dff7576d53.png"
+                },
+                {
+                    "name": "🤔",
+                    "count": 1,
+                    "me": true
+                }
+            ]
+        }"#;
+        let announcement: Announcement =
+            serde_json::from_str(json).expect("Couldn't deserialize announcement");
+        assert_eq!(announcement.id, "8");
+        assert_eq!(announcement.read, Some(true));
+        assert_eq!(announcement.starts_at, None);
+        assert!(announcement.reactions[0].emoji.is_some());
+        assert_eq!(announcement.reactions[1].emoji, None);
+        assert_eq!(announcement.reactions[1].me, Some(true));
+    }
+}
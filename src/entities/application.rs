@@ -0,0 +1,61 @@
+//! Module containing everything related to applications.
+use serde::Deserialize;
+
+/// An application that interfaces with the REST API, as attached to statuses
+/// or returned when verifying an app's credentials.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Application {
+    /// Name of the application.
+    pub name: String,
+    /// Homepage URL of the application.
+    pub website: Option<String>,
+    /// The key used for Web Push notifications, only sent when verifying the
+    /// app's credentials.
+    pub vapid_key: Option<String>,
+    /// The client id of the application, only sent to the application itself.
+    pub client_id: Option<String>,
+    /// The client secret of the application, only sent to the application
+    /// itself.
+    pub client_secret: Option<String>,
+    /// The scopes the application was registered with, sent by Mastodon 4.3
+    /// and later.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// The redirect uris the application was registered with, sent by
+    /// Mastodon 4.3 and later.
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_verified_application() {
+        let json = r#"{
+            "id": "3",
+            "name": "elefren_test",
+            "website": null,
+            "scopes": ["read", "write"],
+            "redirect_uri": "urn:ietf:wg:oauth:2.0:oob",
+            "redirect_uris": ["urn:ietf:wg:oauth:2.0:oob"],
+            "vapid_key": "BCk-QqERU0q-CfYZjcuB6lnyyOYfJ2AifKqfeGIm7Z-HiTU5T9eTG5GxVA0_OH5mMlI4UkkDTpaZwozy0TzdZ2M="
+        }"#;
+        let app: Application = serde_json::from_str(json).expect("Couldn't deserialize app");
+        assert_eq!(app.name, "elefren_test");
+        assert!(app.vapid_key.is_some());
+        assert_eq!(app.client_secret, None);
+        assert_eq!(app.scopes, vec!["read", "write"]);
+    }
+
+    #[test]
+    fn test_deserialize_status_application() {
+        let json = r#"{"name": "Web", "website": null}"#;
+        let app: Application = serde_json::from_str(json).expect("Couldn't deserialize app");
+        assert_eq!(app.name, "Web");
+        assert_eq!(app.vapid_key, None);
+        assert!(app.scopes.is_empty());
+    }
+}
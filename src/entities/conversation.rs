@@ -0,0 +1,60 @@
+//! Module containing everything related to direct conversations.
+use super::{account::Account, status::Status};
+use serde::Deserialize;
+
+/// Represents a conversation with "direct message" visibility.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Conversation {
+    /// The ID of the conversation.
+    pub id: String,
+    /// Participants in the conversation.
+    pub accounts: Vec<Account>,
+    /// Is the conversation currently marked as unread?
+    pub unread: bool,
+    /// The last status in the conversation.
+    pub last_status: Option<Status>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_conversation() {
+        let json = r#"{
+            "id": "418450",
+            "unread": true,
+            "accounts": [
+                {
+                    "id": "482403",
+                    "username": "amic",
+                    "acct": "amic@nulled.red",
+                    "display_name": "",
+                    "locked": false,
+                    "bot": false,
+                    "created_at": "2018-08-13T00:00:00.000Z",
+                    "note": "",
+                    "url": "https://nulled.red/@amic",
+                    "avatar": "https://nulled.red/avatars/original/missing.png",
+                    "avatar_static": "https://nulled.red/avatars/original/missing.png",
+                    "header": "https://nulled.red/headers/original/missing.png",
+                    "header_static": "https://nulled.red/headers/original/missing.png",
+                    "followers_count": 8,
+                    "following_count": 6,
+                    "statuses_count": 5,
+                    "emojis": [],
+                    "fields": []
+                }
+            ],
+            "last_status": null
+        }"#;
+        let conversation: Conversation =
+            serde_json::from_str(json).expect("Couldn't deserialize conversation");
+        assert_eq!(conversation.id, "418450");
+        assert!(conversation.unread);
+        assert_eq!(conversation.last_status, None);
+        assert_eq!(conversation.accounts.len(), 1);
+        assert_eq!(conversation.accounts[0].acct, "amic@nulled.red");
+    }
+}
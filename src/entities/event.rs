@@ -1,4 +1,4 @@
-use crate::entities::{notification::Notification, status::Status};
+use crate::entities::{conversation::Conversation, notification::Notification, status::Status};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
@@ -12,4 +12,6 @@ pub enum Event {
     Delete(String),
     /// FiltersChanged event
     FiltersChanged,
+    /// Conversation event, sent on the direct stream
+    Conversation(Conversation),
 }
@@ -0,0 +1,49 @@
+//! Module containing everything related to featured tags.
+use chrono::prelude::*;
+use serde::Deserialize;
+
+/// Represents a hashtag that is featured on a profile.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FeaturedTag {
+    /// The internal ID of the featured tag in the database.
+    pub id: String,
+    /// The name of the hashtag being featured.
+    pub name: String,
+    /// A link to all statuses by a user that contain this hashtag.
+    pub url: Option<String>,
+    /// The number of authored statuses containing this hashtag.
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub statuses_count: u64,
+    /// The date of the last authored status containing this hashtag.
+    #[serde(default, deserialize_with = "super::optional_date")]
+    pub last_status_at: Option<NaiveDate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_featured_tag() {
+        let older = r#"{
+            "id": "627",
+            "name": "nowplaying",
+            "statuses_count": 70,
+            "last_status_at": "2019-11-15T07:14:43.524Z"
+        }"#;
+        let newer = r#"{
+            "id": "627",
+            "name": "nowplaying",
+            "url": "https://mastodon.social/@trwnh/tagged/nowplaying",
+            "statuses_count": "70",
+            "last_status_at": "2019-11-15"
+        }"#;
+        for json in &[older, newer] {
+            let tag: FeaturedTag =
+                serde_json::from_str(json).expect("Couldn't deserialize featured tag");
+            assert_eq!(tag.statuses_count, 70);
+            assert_eq!(tag.last_status_at, "2019-11-15".parse().ok());
+        }
+    }
+}
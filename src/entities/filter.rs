@@ -1,14 +1,22 @@
+use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// Represents a single Filter
+/// Represents a single Filter, as returned by the deprecated v1 filters API
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Filter {
-    id: String,
-    phrase: String,
-    context: Vec<FilterContext>,
-    expires_at: Option<String>, // TODO: timestamp
-    irreversible: bool,
-    whole_word: bool,
+    /// The ID of the filter in the database.
+    pub id: String,
+    /// The text to be filtered.
+    pub phrase: String,
+    /// The contexts in which the filter should be applied.
+    pub context: Vec<FilterContext>,
+    /// When the filter should no longer be applied.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Whether matching entities should be dropped by the server rather than
+    /// hidden by the client.
+    pub irreversible: bool,
+    /// Whether the phrase should only match whole words.
+    pub whole_word: bool,
 }
 
 /// Represents the various types of Filter contexts
@@ -26,4 +34,116 @@ pub enum FilterContext {
     /// Represents the "thread" context
     #[serde(rename = "thread")]
     Thread,
+    /// Represents the "account" context
+    #[serde(rename = "account")]
+    Account,
+}
+
+/// Represents a user-defined filter, as returned by the v2 filters API
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FilterV2 {
+    /// The ID of the filter in the database.
+    pub id: String,
+    /// A title given by the user to name the filter.
+    pub title: String,
+    /// The contexts in which the filter should be applied.
+    pub context: Vec<FilterContext>,
+    /// When the filter should no longer be applied.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// The action to be taken when a status matches this filter.
+    pub filter_action: FilterAction,
+    /// The keywords grouped under this filter.
+    #[serde(default)]
+    pub keywords: Vec<FilterKeyword>,
+    /// The statuses grouped under this filter.
+    #[serde(default)]
+    pub statuses: Vec<FilterStatus>,
+}
+
+/// The action to be taken when a status matches a filter
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterAction {
+    /// Show a warning that identifies the matching filter by title
+    Warn,
+    /// Do not show this status if it is received
+    Hide,
+    /// Hide the media attachments of the status behind a warning
+    Blur,
+    /// An action added in a newer server version. Can't be sent back to the
+    /// server.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// A keyword that, if matched, should cause a filter action
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FilterKeyword {
+    /// The ID of the keyword in the database.
+    pub id: String,
+    /// The phrase to be matched against.
+    pub keyword: String,
+    /// Whether the keyword should only match whole words.
+    pub whole_word: bool,
+}
+
+/// A status ID that, if matched, should cause a filter action
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FilterStatus {
+    /// The ID of the status filter in the database.
+    pub id: String,
+    /// The ID of the filtered status.
+    pub status_id: String,
+}
+
+/// A filter whose keywords or statuses matched a given status
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FilterResult {
+    /// The filter that was matched.
+    pub filter: FilterV2,
+    /// The keywords within the filter that were matched.
+    pub keyword_matches: Option<Vec<String>>,
+    /// The status IDs within the filter that were matched.
+    pub status_matches: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_filter_result() {
+        let json = r#"{
+            "filter": {
+                "id": "3",
+                "title": "Hide completely",
+                "context": ["home"],
+                "expires_at": "2022-09-20T17:27:39.296Z",
+                "filter_action": "hide"
+            },
+            "keyword_matches": ["bad word"],
+            "status_matches": null
+        }"#;
+        let result: FilterResult =
+            serde_json::from_str(json).expect("Couldn't deserialize filter result");
+        assert_eq!(result.filter.filter_action, FilterAction::Hide);
+        assert_eq!(result.filter.context, vec![FilterContext::Home]);
+        assert!(result.filter.keywords.is_empty());
+        assert_eq!(result.keyword_matches, Some(vec!["bad word".to_string()]));
+    }
+
+    #[test]
+    fn test_deserialize_unknown_filter_action() {
+        let json = r#"{
+            "id": "4",
+            "title": "Something new",
+            "context": ["public"],
+            "expires_at": null,
+            "filter_action": "shimmer"
+        }"#;
+        let filter: FilterV2 = serde_json::from_str(json).expect("Couldn't deserialize filter");
+        assert_eq!(filter.filter_action, FilterAction::Unknown);
+        assert!(serde_json::to_string(&FilterAction::Unknown).is_err());
+    }
 }
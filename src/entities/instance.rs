@@ -1,5 +1,6 @@
 //! Module containing everything related to an instance.
 use super::account::Account;
+use chrono::prelude::*;
 use serde::Deserialize;
 
 /// A struct containing info of an instance.
@@ -40,7 +41,267 @@ pub struct StreamingApi {
 /// Statistics about the Mastodon instance.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
 pub struct Stats {
-    user_count: u64,
-    status_count: u64,
-    domain_count: u64,
+    /// Users registered on this instance.
+    pub user_count: u64,
+    /// Statuses authored by users on this instance.
+    pub status_count: u64,
+    /// Other instances that this instance knows about.
+    pub domain_count: u64,
+}
+
+/// A struct containing info of an instance, as returned by the v2 API.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct InstanceV2 {
+    /// The domain name of the instance.
+    pub domain: String,
+    /// The title of the website.
+    pub title: String,
+    /// The version of Mastodon installed on the instance.
+    pub version: String,
+    /// The URL for the source code of the software running on this instance.
+    pub source_url: String,
+    /// A short, plain-text description defined by the admin.
+    pub description: String,
+    /// Usage data for this instance.
+    pub usage: Usage,
+    /// An image used to represent this instance.
+    pub thumbnail: Thumbnail,
+    /// Primary languages of the website and its staff.
+    pub languages: Vec<String>,
+    /// Configured values and limits for this website.
+    pub configuration: Configuration,
+    /// Information about registering for this website.
+    pub registrations: Registrations,
+    /// Hints related to contacting a representative of the website.
+    pub contact: Contact,
+    /// An itemized list of rules for this website.
+    pub rules: Vec<Rule>,
+}
+
+/// Usage data for an instance.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct Usage {
+    /// Usage data related to users on this instance.
+    pub users: UsageUsers,
+}
+
+/// Usage data related to users on an instance.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct UsageUsers {
+    /// The number of active users in the past 4 weeks.
+    pub active_month: u64,
+}
+
+/// An image used to represent an instance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Thumbnail {
+    /// The URL for the thumbnail image.
+    pub url: String,
+    /// A hash computed by the BlurHash algorithm, for generating colorful
+    /// preview thumbnails when media has not been downloaded yet.
+    pub blurhash: Option<String>,
+}
+
+/// Configured values and limits for an instance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Configuration {
+    /// URLs of interest for clients apps.
+    pub urls: ConfigurationUrls,
+    /// Limits related to accounts.
+    pub accounts: Option<AccountsConfiguration>,
+    /// Limits related to authoring statuses.
+    pub statuses: StatusesConfiguration,
+    /// Hints for which attachments will be accepted.
+    pub media_attachments: MediaAttachmentsConfiguration,
+    /// Limits related to polls.
+    pub polls: PollsConfiguration,
+    /// Hints related to translation.
+    pub translation: Option<TranslationConfiguration>,
+}
+
+/// URLs of interest for client apps.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ConfigurationUrls {
+    /// The Websockets URL for connecting to the streaming API.
+    pub streaming: String,
+}
+
+/// Limits related to accounts.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct AccountsConfiguration {
+    /// The maximum number of featured tags allowed for each account.
+    pub max_featured_tags: u64,
+}
+
+/// Limits related to authoring statuses.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct StatusesConfiguration {
+    /// The maximum number of allowed characters per status.
+    pub max_characters: u64,
+    /// The maximum number of media attachments that can be added to a status.
+    pub max_media_attachments: u64,
+    /// Each URL in a status will be assumed to be exactly this many
+    /// characters.
+    pub characters_reserved_per_url: u64,
+}
+
+/// Hints for which attachments will be accepted.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MediaAttachmentsConfiguration {
+    /// Contains MIME types that can be uploaded.
+    pub supported_mime_types: Vec<String>,
+    /// The maximum size of any uploaded image, in bytes.
+    pub image_size_limit: u64,
+    /// The maximum number of pixels (width times height) for image uploads.
+    pub image_matrix_limit: u64,
+    /// The maximum size of any uploaded video, in bytes.
+    pub video_size_limit: u64,
+    /// The maximum frame rate for any uploaded video.
+    pub video_frame_rate_limit: u64,
+    /// The maximum number of pixels (width times height) for video uploads.
+    pub video_matrix_limit: u64,
+}
+
+/// Limits related to polls.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct PollsConfiguration {
+    /// Each poll is allowed to have up to this many options.
+    pub max_options: u64,
+    /// Each poll option is allowed to have this many characters.
+    pub max_characters_per_option: u64,
+    /// The shortest allowed poll duration, in seconds.
+    pub min_expiration: u64,
+    /// The longest allowed poll duration, in seconds.
+    pub max_expiration: u64,
+}
+
+/// Hints related to translation.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct TranslationConfiguration {
+    /// Whether the Translations API is available on this instance.
+    pub enabled: bool,
+}
+
+/// Information about registering for an instance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Registrations {
+    /// Whether registrations are enabled.
+    pub enabled: bool,
+    /// Whether registrations require moderator approval.
+    pub approval_required: bool,
+    /// A custom message to be shown when registrations are closed.
+    pub message: Option<String>,
+}
+
+/// Hints related to contacting a representative of an instance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Contact {
+    /// An email address that can be messaged regarding inquiries or issues.
+    pub email: String,
+    /// An account that can be contacted natively over the network regarding
+    /// inquiries or issues.
+    pub account: Option<Account>,
+}
+
+/// A rule that the users of an instance should follow.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Rule {
+    /// The identifier for the rule.
+    pub id: String,
+    /// The rule to be followed.
+    pub text: String,
+    /// Longer-form description of the rule.
+    pub hint: Option<String>,
+}
+
+/// The extended description of an instance.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ExtendedDescription {
+    /// When the extended description was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// The rendered HTML content of the extended description.
+    pub content: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_instance_v2() {
+        let json = r#"{
+            "domain": "mastodon.social",
+            "title": "Mastodon",
+            "version": "4.0.0rc1",
+            "source_url": "https://github.com/mastodon/mastodon",
+            "description": "The original server operated by the Mastodon gGmbH non-profit",
+            "usage": {
+                "users": {
+                    "active_month": 123122
+                }
+            },
+            "thumbnail": {
+                "url": "https://files.mastodon.social/site_uploads/files/000/000/001/@1x/57c12f441d083cde.png",
+                "blurhash": "UeKUpFxuo~R%0nW;WCnhF6RjaJt757oJodS$",
+                "versions": {
+                    "@1x": "https://files.mastodon.social/site_uploads/files/000/000/001/@1x/57c12f441d083cde.png"
+                }
+            },
+            "languages": ["en"],
+            "configuration": {
+                "urls": {
+                    "streaming": "wss://mastodon.social"
+                },
+                "accounts": {
+                    "max_featured_tags": 10
+                },
+                "statuses": {
+                    "max_characters": 500,
+                    "max_media_attachments": 4,
+                    "characters_reserved_per_url": 23
+                },
+                "media_attachments": {
+                    "supported_mime_types": ["image/jpeg", "image/png"],
+                    "image_size_limit": 10485760,
+                    "image_matrix_limit": 16777216,
+                    "video_size_limit": 41943040,
+                    "video_frame_rate_limit": 60,
+                    "video_matrix_limit": 2304000
+                },
+                "polls": {
+                    "max_options": 4,
+                    "max_characters_per_option": 50,
+                    "min_expiration": 300,
+                    "max_expiration": 2629746
+                },
+                "translation": {
+                    "enabled": true
+                }
+            },
+            "registrations": {
+                "enabled": false,
+                "approval_required": false,
+                "message": null
+            },
+            "contact": {
+                "email": "staff@mastodon.social",
+                "account": null
+            },
+            "rules": [
+                {
+                    "id": "1",
+                    "text": "Sexually explicit or violent media must be marked as sensitive when posting"
+                }
+            ]
+        }"#;
+        let instance: InstanceV2 =
+            serde_json::from_str(json).expect("Couldn't deserialize instance");
+        assert_eq!(instance.configuration.statuses.max_characters, 500);
+        assert_eq!(instance.configuration.polls.max_options, 4);
+        assert_eq!(instance.usage.users.active_month, 123122);
+        assert!(!instance.registrations.enabled);
+        assert_eq!(instance.rules[0].id, "1");
+        assert_eq!(instance.rules[0].hint, None);
+    }
 }
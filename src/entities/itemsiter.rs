@@ -14,6 +14,7 @@ use serde::Deserialize;
 /// #   client_secret: "".into(),
 /// #   redirect: "".into(),
 /// #   token: "".into(),
+/// #   scopes: None,
 /// # };
 /// let client = Mastodon::from(data);
 /// let statuses = client.statuses("user-id", None)?;
@@ -1,7 +1,26 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
 /// Used for ser/de of list resources
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct List {
-    id: String,
-    title: String,
+    /// The internal database ID of the list.
+    pub id: String,
+    /// The user-defined title of the list.
+    pub title: String,
+    /// Which replies should be shown in the list.
+    pub replies_policy: Option<RepliesPolicy>,
+    /// Whether members of this list need to get removed from the "Home" feed.
+    pub exclusive: Option<bool>,
+}
+
+/// Which replies should be shown in a list.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RepliesPolicy {
+    /// Show replies to any followed user
+    Followed,
+    /// Show replies to members of the list
+    List,
+    /// Show replies to no one
+    None,
 }
@@ -0,0 +1,81 @@
+//! Module containing everything related to timeline read markers.
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The last read position within a timeline.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Marker {
+    /// The ID of the most recently viewed entity.
+    pub last_read_id: String,
+    /// An incrementing counter, used for locking to prevent write conflicts.
+    pub version: u64,
+    /// The timestamp of when the marker was set.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The saved read positions, keyed by timeline.
+///
+/// Only the timelines that were asked for (or updated) are present.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Markers {
+    /// The marker for the home timeline.
+    pub home: Option<Marker>,
+    /// The marker for the notifications timeline.
+    pub notifications: Option<Marker>,
+}
+
+/// The timelines that support read markers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkerTimeline {
+    /// The home timeline
+    Home,
+    /// The notifications timeline
+    Notifications,
+}
+
+impl MarkerTimeline {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            MarkerTimeline::Home => "home",
+            MarkerTimeline::Notifications => "notifications",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_markers() {
+        let json = r#"{
+            "notifications": {
+                "last_read_id": "35098814",
+                "version": 361,
+                "updated_at": "2019-11-26T22:37:25.239Z"
+            }
+        }"#;
+        let markers: Markers = serde_json::from_str(json).expect("Couldn't deserialize markers");
+        assert_eq!(markers.home, None);
+        let marker = markers.notifications.expect("No notifications marker");
+        assert_eq!(marker.last_read_id, "35098814");
+        assert_eq!(marker.version, 361);
+        assert_eq!(
+            marker.updated_at,
+            "2019-11-26T22:37:25.239Z"
+                .parse::<DateTime<Utc>>()
+                .expect("Couldn't parse date")
+        );
+    }
+
+    #[test]
+    fn test_serialize_timeline() {
+        assert_eq!(
+            serde_json::to_string(&MarkerTimeline::Notifications).expect("Couldn't serialize"),
+            r#""notifications""#
+        );
+        assert_eq!(MarkerTimeline::Home.as_str(), "home");
+    }
+}
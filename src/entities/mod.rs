@@ -1,17 +1,28 @@
-use serde::Deserialize;
+use chrono::prelude::*;
+use serde::{de, Deserialize};
 
 /// Data structures for ser/de of account-related resources
 pub mod account;
 /// Data structures for ser/de of activity-related resources
 pub mod activity;
+/// Data structures for ser/de of admin-related resources
+pub mod admin;
+/// Data structures for ser/de of announcement-related resources
+pub mod announcement;
+/// Data structures for ser/de of application-related resources
+pub mod application;
 /// Data structures for ser/de of attachment-related resources
 pub mod attachment;
 /// Data structures for ser/de of card-related resources
 pub mod card;
 /// Data structures for ser/de of contetx-related resources
 pub mod context;
+/// Data structures for ser/de of conversation-related resources
+pub mod conversation;
 /// Data structures for ser/de of streaming events
 pub mod event;
+/// Data structures for ser/de of featured-tag-related resources
+pub mod featured_tag;
 /// Data structures for ser/de of filter-related resources
 pub mod filter;
 /// Data structures for ser/de of instance-related resources
@@ -19,18 +30,24 @@ pub mod instance;
 pub(crate) mod itemsiter;
 /// Data structures for ser/de of list-related resources
 pub mod list;
+/// Data structures for ser/de of marker-related resources
+pub mod marker;
 /// Data structures for ser/de of mention-related resources
 pub mod mention;
 /// Data structures for ser/de of notification-related resources
 pub mod notification;
 /// Data structures for ser/de of poll resources
 pub mod poll;
+/// Data structures for ser/de of preference-related resources
+pub mod preferences;
 /// Data structures for ser/de of push-subscription-related resources
 pub mod push;
 /// Data structures for ser/de of relationship-related resources
 pub mod relationship;
 /// Data structures for ser/de of report-related resources
 pub mod report;
+/// Data structures for ser/de of scheduled-status-related resources
+pub mod scheduled_status;
 /// Data structures for ser/de of search-related resources
 pub mod search_result;
 /// Data structures for ser/de of status-related resources
@@ -40,26 +57,77 @@ pub mod status;
 #[derive(Deserialize, Debug, Copy, Clone, PartialEq)]
 pub struct Empty {}
 
+// Some numeric fields are sent as strings by some servers (or some server
+// versions), so accept either.
+pub(crate) fn string_or_u64<'de, D: de::Deserializer<'de>>(
+    val: D,
+) -> ::std::result::Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum U64OrString {
+        U64(u64),
+        Str(String),
+    }
+
+    Ok(match U64OrString::deserialize(val)? {
+        U64OrString::U64(n) => n,
+        U64OrString::Str(ref s) => s
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(s), &"a number"))?,
+    })
+}
+
+// Dates are sent either as a full timestamp or as a bare `YYYY-MM-DD`
+// depending on the server version, so accept either and keep the date.
+pub(crate) fn optional_date<'de, D: de::Deserializer<'de>>(
+    val: D,
+) -> ::std::result::Result<Option<NaiveDate>, D::Error> {
+    Ok(match Option::<String>::deserialize(val)? {
+        None => None,
+        Some(ref s) => Some(
+            s.parse::<DateTime<Utc>>()
+                .map(|datetime| datetime.naive_utc().date())
+                .or_else(|_| s.parse::<NaiveDate>())
+                .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(s), &"a date"))?,
+        ),
+    })
+}
+
 /// The purpose of this module is to alleviate imports of many common
 /// structs by adding a glob import to the top of mastodon heavy
 /// modules:
 pub mod prelude {
     pub use super::{
         account::{Account, Source},
+        activity::Activity,
+        admin::AdminAccount,
+        announcement::{Announcement, AnnouncementReaction},
+        application::Application,
         attachment::{Attachment, MediaType},
         card::Card,
         context::Context,
+        conversation::Conversation,
         event::Event,
-        filter::{Filter, FilterContext},
+        featured_tag::FeaturedTag,
+        filter::{Filter, FilterContext, FilterKeyword, FilterStatus, FilterV2},
         instance::*,
         list::List,
+        marker::{Marker, MarkerTimeline, Markers},
         mention::Mention,
-        notification::Notification,
+        notification::{
+            AccountWarning,
+            Notification,
+            NotificationType,
+            RelationshipSeveranceEvent,
+        },
+        poll::Poll,
+        preferences::Preferences,
         push::Subscription,
         relationship::Relationship,
         report::Report,
+        scheduled_status::{NewStatusResponse, ScheduledStatus},
         search_result::{SearchResult, SearchResultV2},
-        status::{Application, Emoji, Status},
+        status::{Emoji, Status, StatusEdit, StatusSource, Tag},
         Empty,
     };
 }
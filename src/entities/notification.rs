@@ -1,8 +1,9 @@
 //! Module containing all info about notifications.
 
-use super::{account::Account, status::Status};
+use super::{account::Account, report::Report, status::Status};
 use chrono::prelude::*;
-use serde::Deserialize;
+use serde::{de, Deserialize};
+use std::fmt;
 
 /// A struct containing info about a notification.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -18,18 +19,330 @@ pub struct Notification {
     pub account: Account,
     /// The Status associated with the notification, if applicable.
     pub status: Option<Status>,
+    /// The Report associated with an `admin.report` notification.
+    pub report: Option<Report>,
+    /// The event that severed relationships, for a `severed_relationships`
+    /// notification.
+    pub event: Option<RelationshipSeveranceEvent>,
+    /// The warning sent by a moderator, for a `moderation_warning`
+    /// notification.
+    pub moderation_warning: Option<AccountWarning>,
 }
 
-/// The type of notification.
+/// An event that severed some of the application client's follow
+/// relationships.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct RelationshipSeveranceEvent {
+    /// The ID of the event.
+    pub id: String,
+    /// What caused the relationships to be severed.
+    #[serde(rename = "type")]
+    pub event_type: RelationshipSeveranceEventType,
+    /// Whether the list of severed relationships is unavailable because the
+    /// underlying issue has been purged.
+    pub purged: bool,
+    /// The name of the target of the event, a domain or an account handle.
+    pub target_name: String,
+    /// Number of follower accounts removed.
+    pub followers_count: Option<u64>,
+    /// Number of followed accounts removed.
+    pub following_count: Option<u64>,
+    /// When the event took place.
+    pub created_at: DateTime<Utc>,
+}
+
+/// What caused relationships to be severed.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipSeveranceEventType {
+    /// A moderator suspended a whole domain
+    DomainBlock,
+    /// The user blocked a whole domain
+    UserDomainBlock,
+    /// A moderator suspended a specific account
+    AccountSuspension,
+    /// A type this version of the library does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// A moderation action taken against the application client's account.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AccountWarning {
+    /// The ID of the warning.
+    pub id: String,
+    /// The action taken against the account.
+    pub action: AccountWarningAction,
+    /// The message from the moderator to the user.
+    pub text: String,
+    /// The IDs of the statuses the action is about, if any.
+    pub status_ids: Option<Vec<String>>,
+    /// The account the action was taken against.
+    pub target_account: Account,
+    /// The appeal against the action, if one was submitted.
+    pub appeal: Option<Appeal>,
+    /// When the warning was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// The action taken by a moderator.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
+pub enum AccountWarningAction {
+    /// No action was taken, this is a simple warning
+    None,
+    /// The account has been disabled
+    Disable,
+    /// Specific statuses have been marked as sensitive
+    MarkStatusesAsSensitive,
+    /// Specific statuses have been deleted
+    DeleteStatuses,
+    /// All future statuses will be marked as sensitive
+    Sensitive,
+    /// The account has been limited
+    Silence,
+    /// The account has been suspended
+    Suspend,
+    /// An action this version of the library does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// An appeal submitted against a moderation action.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Appeal {
+    /// The text of the appeal.
+    pub text: String,
+    /// Where the appeal is at.
+    pub state: AppealState,
+}
+
+/// The state of an appeal.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AppealState {
+    /// The appeal was approved
+    Approved,
+    /// The appeal was rejected
+    Rejected,
+    /// The appeal hasn't been handled yet
+    Pending,
+    /// A state this version of the library does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of notification.
+#[derive(Debug, Clone, PartialEq)]
 pub enum NotificationType {
     /// Someone mentioned the application client in another status.
     Mention,
+    /// Someone the application client enabled notifications for has posted a
+    /// status.
+    Status,
     /// Someone reblogged one of the application client's statuses.
     Reblog,
-    /// Someone favourited one of the application client's statuses.
-    Favourite,
     /// Someone followed the application client.
     Follow,
+    /// Someone requested to follow the application client.
+    FollowRequest,
+    /// Someone favourited one of the application client's statuses.
+    Favourite,
+    /// A poll the application client voted in or created has ended.
+    Poll,
+    /// A status the application client interacted with has been edited.
+    Update,
+    /// Someone signed up (admin only).
+    AdminSignUp,
+    /// A new report has been filed (admin only).
+    AdminReport,
+    /// Some of the application client's follow relationships have been severed
+    /// as a result of a moderation or block event.
+    SeveredRelationships,
+    /// A moderator has taken action against the application client's account.
+    ModerationWarning,
+    /// A notification type this version of the library does not know about.
+    Unknown(String),
+}
+
+impl NotificationType {
+    pub(crate) fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Mention => "mention",
+            NotificationType::Status => "status",
+            NotificationType::Reblog => "reblog",
+            NotificationType::Follow => "follow",
+            NotificationType::FollowRequest => "follow_request",
+            NotificationType::Favourite => "favourite",
+            NotificationType::Poll => "poll",
+            NotificationType::Update => "update",
+            NotificationType::AdminSignUp => "admin.sign_up",
+            NotificationType::AdminReport => "admin.report",
+            NotificationType::SeveredRelationships => "severed_relationships",
+            NotificationType::ModerationWarning => "moderation_warning",
+            NotificationType::Unknown(ref other) => other,
+        }
+    }
+}
+
+impl From<&str> for NotificationType {
+    fn from(s: &str) -> NotificationType {
+        match s {
+            "mention" => NotificationType::Mention,
+            "status" => NotificationType::Status,
+            "reblog" => NotificationType::Reblog,
+            "follow" => NotificationType::Follow,
+            "follow_request" => NotificationType::FollowRequest,
+            "favourite" => NotificationType::Favourite,
+            "poll" => NotificationType::Poll,
+            "update" => NotificationType::Update,
+            "admin.sign_up" => NotificationType::AdminSignUp,
+            "admin.report" => NotificationType::AdminReport,
+            "severed_relationships" => NotificationType::SeveredRelationships,
+            "moderation_warning" => NotificationType::ModerationWarning,
+            other => NotificationType::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NotificationType {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> ::std::result::Result<NotificationType, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(NotificationType::from(&s[..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_known_types() {
+        let types = [
+            ("\"mention\"", NotificationType::Mention),
+            ("\"follow_request\"", NotificationType::FollowRequest),
+            ("\"poll\"", NotificationType::Poll),
+            ("\"update\"", NotificationType::Update),
+            ("\"admin.sign_up\"", NotificationType::AdminSignUp),
+            ("\"admin.report\"", NotificationType::AdminReport),
+        ];
+        for (json, expected) in types.iter() {
+            let t: NotificationType =
+                serde_json::from_str(json).expect("Couldn't deserialize notification type");
+            assert_eq!(&t, expected);
+            assert_eq!(&format!("\"{}\"", t), json);
+        }
+    }
+
+    const ACCOUNT: &str = r#"{
+        "id": "1",
+        "username": "someone",
+        "acct": "someone",
+        "display_name": "",
+        "locked": false,
+        "bot": false,
+        "created_at": "2022-09-08T00:00:00.000Z",
+        "note": "",
+        "url": "https://example.com/@someone",
+        "avatar": "https://example.com/avatars/original/missing.png",
+        "avatar_static": "https://example.com/avatars/original/missing.png",
+        "header": "https://example.com/headers/original/missing.png",
+        "header_static": "https://example.com/headers/original/missing.png",
+        "followers_count": 0,
+        "following_count": 0,
+        "statuses_count": 0,
+        "emojis": [],
+        "fields": []
+    }"#;
+
+    #[test]
+    fn test_deserialize_severed_relationships() {
+        let json = format!(
+            r#"{{
+                "id": "38",
+                "type": "severed_relationships",
+                "created_at": "2024-03-22T13:35:49.000Z",
+                "account": {},
+                "event": {{
+                    "id": "1",
+                    "type": "domain_block",
+                    "purged": false,
+                    "target_name": "bad.example",
+                    "followers_count": 2,
+                    "following_count": 1,
+                    "created_at": "2024-03-22T13:35:49.000Z"
+                }}
+            }}"#,
+            ACCOUNT
+        );
+        let notification: Notification =
+            serde_json::from_str(&json).expect("Couldn't deserialize notification");
+        assert_eq!(
+            notification.notification_type,
+            NotificationType::SeveredRelationships
+        );
+        let event = notification.event.expect("No severance event");
+        assert_eq!(
+            event.event_type,
+            RelationshipSeveranceEventType::DomainBlock
+        );
+        assert_eq!(event.target_name, "bad.example");
+        assert_eq!(event.followers_count, Some(2));
+        assert_eq!(notification.moderation_warning, None);
+    }
+
+    #[test]
+    fn test_deserialize_moderation_warning() {
+        let json = format!(
+            r#"{{
+                "id": "39",
+                "type": "moderation_warning",
+                "created_at": "2024-03-22T13:35:49.000Z",
+                "account": {account},
+                "moderation_warning": {{
+                    "id": "2",
+                    "action": "mark_statuses_as_sensitive",
+                    "text": "Please use content warnings",
+                    "status_ids": ["110"],
+                    "target_account": {account},
+                    "appeal": {{"text": "It was fine", "state": "pending"}},
+                    "created_at": "2024-03-22T13:35:49.000Z"
+                }}
+            }}"#,
+            account = ACCOUNT
+        );
+        let notification: Notification =
+            serde_json::from_str(&json).expect("Couldn't deserialize notification");
+        let warning = notification
+            .moderation_warning
+            .expect("No moderation warning");
+        assert_eq!(
+            warning.action,
+            AccountWarningAction::MarkStatusesAsSensitive
+        );
+        assert_eq!(warning.text, "Please use content warnings");
+        assert_eq!(warning.status_ids, Some(vec!["110".to_string()]));
+        assert_eq!(
+            warning.appeal.map(|appeal| appeal.state),
+            Some(AppealState::Pending)
+        );
+        assert_eq!(notification.event, None);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_type() {
+        let t: NotificationType =
+            serde_json::from_str("\"quote\"").expect("Couldn't deserialize notification type");
+        assert_eq!(t, NotificationType::Unknown("quote".to_string()));
+        assert_eq!(t.as_str(), "quote");
+    }
 }
@@ -1,4 +1,5 @@
 use crate::entities::status::Emoji;
+use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Represents a poll attached to a status.
@@ -6,8 +7,8 @@ use serde::{Deserialize, Serialize};
 pub struct Poll {
     /// The ID of the poll in the database.
     pub id: String,
-    /// When the poll ends.
-    pub expires_at: String, // Datetime??
+    /// When the poll ends, if it has an expiry.
+    pub expires_at: Option<DateTime<Utc>>,
     /// Is the poll currently expired?
     pub expired: bool,
     /// Does the poll allow multiple-choice answers?
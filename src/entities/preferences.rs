@@ -0,0 +1,59 @@
+//! Module containing everything related to a user's preferences.
+use crate::status_builder::Visibility;
+use serde::Deserialize;
+
+/// The preferences of the authenticated user, set from the web interface.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Preferences {
+    /// Default visibility for new posts.
+    #[serde(rename = "posting:default:visibility")]
+    pub default_visibility: Visibility,
+    /// Default sensitivity flag for new posts.
+    #[serde(rename = "posting:default:sensitive")]
+    pub default_sensitive: bool,
+    /// Default language for new posts, as an ISO 639-1 code.
+    #[serde(rename = "posting:default:language")]
+    pub default_language: Option<String>,
+    /// Whether media attachments should be automatically displayed or blurred
+    /// and hidden.
+    #[serde(rename = "reading:expand:media")]
+    pub expand_media: ExpandMedia,
+    /// Whether CWs should be expanded by default.
+    #[serde(rename = "reading:expand:spoilers")]
+    pub expand_spoilers: bool,
+}
+
+/// How media attachments should be displayed.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpandMedia {
+    /// Hide media marked as sensitive
+    Default,
+    /// Always show all media by default, regardless of sensitivity
+    ShowAll,
+    /// Always hide all media by default, regardless of sensitivity
+    HideAll,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_preferences() {
+        let json = r#"{
+            "posting:default:visibility": "unlisted",
+            "posting:default:sensitive": false,
+            "posting:default:language": null,
+            "reading:expand:media": "show_all",
+            "reading:expand:spoilers": true
+        }"#;
+        let preferences: Preferences =
+            serde_json::from_str(json).expect("Couldn't deserialize preferences");
+        assert_eq!(preferences.default_visibility, Visibility::Unlisted);
+        assert_eq!(preferences.default_language, None);
+        assert_eq!(preferences.expand_media, ExpandMedia::ShowAll);
+        assert!(preferences.expand_spoilers);
+    }
+}
@@ -1,5 +1,6 @@
 //! module containing everything relating to a relationship with
 //! another account.
+use chrono::prelude::*;
 use serde::Deserialize;
 
 /// A struct containing information about a relationship with another account.
@@ -29,4 +30,15 @@ pub struct Relationship {
     /// making it `Option<bool>` here means we shouldn't get deser errors when
     /// making calls to pleroma or mastodon<2.5.0 instances
     pub endorsed: Option<bool>,
+    /// Whether the user has enabled notifications for the account's statuses
+    pub notifying: Option<bool>,
+    /// The languages of the account's statuses the user follows, if the
+    /// follow is restricted to some languages
+    pub languages: Option<Vec<String>>,
+    /// Whether the account is blocking the user
+    pub blocked_by: Option<bool>,
+    /// The user's private note on the account
+    pub note: Option<String>,
+    /// When the mute of the account expires, if it is temporary
+    pub muting_expires_at: Option<DateTime<Utc>>,
 }
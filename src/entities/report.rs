@@ -1,11 +1,113 @@
 //! module containing information about a finished report of a user.
-use serde::Deserialize;
+use super::account::Account;
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// A struct containing info about a report.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Report {
     /// The ID of the report.
     pub id: String,
-    /// The action taken in response to the report.
-    pub action_taken: String,
+    /// Whether an action was taken yet.
+    pub action_taken: bool,
+    /// When an action was taken against the report.
+    pub action_taken_at: Option<DateTime<Utc>>,
+    /// The generic reason for the report, not sent by servers older than
+    /// Mastodon 3.5.
+    pub category: Option<ReportCategory>,
+    /// The reason for the report.
+    #[serde(default)]
+    pub comment: String,
+    /// Whether the report was forwarded to a remote domain.
+    pub forwarded: Option<bool>,
+    /// When the report was created.
+    pub created_at: Option<DateTime<Utc>>,
+    /// IDs of statuses that have been attached to this report for additional
+    /// context.
+    pub status_ids: Option<Vec<String>>,
+    /// IDs of the rules that have been cited as a violation by this report.
+    pub rule_ids: Option<Vec<String>>,
+    /// The account that was reported.
+    pub target_account: Option<Account>,
+}
+
+/// The generic reason for a report.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportCategory {
+    /// Unwanted or repetitive content
+    Spam,
+    /// Illegal content
+    Legal,
+    /// A specific rule was violated
+    Violation,
+    /// Some other reason, or a category this library doesn't know about
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_report() {
+        let json = r#"{
+            "id": "48914",
+            "action_taken": false,
+            "action_taken_at": null,
+            "category": "violation",
+            "comment": "",
+            "forwarded": true,
+            "created_at": "2022-08-25T09:56:16.763Z",
+            "status_ids": ["108882889550545820"],
+            "rule_ids": ["3"],
+            "target_account": {
+                "id": "108366849347798387",
+                "username": "Baluke",
+                "acct": "Baluke",
+                "display_name": "Baluke Dural",
+                "locked": false,
+                "bot": false,
+                "created_at": "2022-05-26T00:00:00.000Z",
+                "note": "",
+                "url": "https://mastodon.social/@Baluke",
+                "avatar": "https://mastodon.social/avatars/original/missing.png",
+                "avatar_static": "https://mastodon.social/avatars/original/missing.png",
+                "header": "https://mastodon.social/headers/original/missing.png",
+                "header_static": "https://mastodon.social/headers/original/missing.png",
+                "followers_count": 6,
+                "following_count": 1,
+                "statuses_count": 41,
+                "emojis": [],
+                "fields": []
+            }
+        }"#;
+        let report: Report = serde_json::from_str(json).expect("Couldn't deserialize report");
+        assert_eq!(report.category, Some(ReportCategory::Violation));
+        assert_eq!(report.forwarded, Some(true));
+        assert_eq!(report.rule_ids, Some(vec!["3".to_string()]));
+        assert_eq!(
+            report.target_account.map(|account| account.username),
+            Some("Baluke".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy_report() {
+        let json = r#"{"id": "1", "action_taken": true}"#;
+        let report: Report = serde_json::from_str(json).expect("Couldn't deserialize report");
+        assert!(report.action_taken);
+        assert_eq!(report.category, None);
+        assert_eq!(report.comment, "");
+        assert_eq!(report.target_account, None);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_category() {
+        let category: ReportCategory =
+            serde_json::from_str(r#""impersonation""#).expect("Couldn't deserialize category");
+        assert_eq!(category, ReportCategory::Other);
+    }
 }
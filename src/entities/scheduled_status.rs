@@ -0,0 +1,115 @@
+//! Module containing everything related to a scheduled status.
+use super::{attachment::Attachment, status::Status};
+use crate::status_builder::Visibility;
+use chrono::prelude::*;
+use serde::Deserialize;
+
+/// Represents a status that will be published at a future scheduled date.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduledStatus {
+    /// ID of the scheduled status in the database.
+    pub id: String,
+    /// The time the status will be published.
+    pub scheduled_at: DateTime<Utc>,
+    /// The parameters that were used when scheduling the status, to be used
+    /// when the status is posted.
+    pub params: ScheduledStatusParams,
+    /// Media that will be attached when the status is posted.
+    pub media_attachments: Vec<Attachment>,
+}
+
+/// The parameters that were used when scheduling a status.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduledStatusParams {
+    /// Text to be used as status content.
+    pub text: String,
+    /// Poll to be attached to the status.
+    pub poll: Option<ScheduledPollParams>,
+    /// IDs of the media attachments that will be attached to the status.
+    pub media_ids: Option<Vec<String>>,
+    /// Whether the status will be marked as sensitive.
+    pub sensitive: Option<bool>,
+    /// The text of the content warning or summary for the status.
+    pub spoiler_text: Option<String>,
+    /// The visibility that the status will have once it is posted.
+    pub visibility: Option<Visibility>,
+    /// ID of the status being replied to.
+    pub in_reply_to_id: Option<String>,
+    /// The language that will be used for the status.
+    pub language: Option<String>,
+    /// ID of the application that scheduled the status.
+    pub application_id: Option<u64>,
+    /// Idempotency key to prevent duplicate statuses.
+    pub idempotency: Option<String>,
+    /// Whether status creation is subject to rate limiting.
+    pub with_rate_limit: Option<bool>,
+}
+
+/// The poll parameters that were used when scheduling a status.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduledPollParams {
+    /// The poll options to be used.
+    pub options: Vec<String>,
+    /// How many seconds the poll should last before closing.
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub expires_in: u64,
+    /// Whether the poll allows multiple choices.
+    pub multiple: Option<bool>,
+    /// Whether the poll should hide total votes until after voting has ended.
+    pub hide_totals: Option<bool>,
+}
+
+/// The result of posting a new status, which is either published right away
+/// or, if `scheduled_at` was set, queued for later.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum NewStatusResponse {
+    /// The status was published immediately
+    Status(Status),
+    /// The status was scheduled to be published later
+    Scheduled(ScheduledStatus),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_deserialize_scheduled_response() {
+        let json = r#"{
+            "id": "3221",
+            "scheduled_at": "2019-12-05T12:33:01.000Z",
+            "params": {
+                "poll": {
+                    "options": ["yes", "no"],
+                    "expires_in": "300",
+                    "multiple": false,
+                    "hide_totals": false
+                },
+                "text": "test content",
+                "media_ids": null,
+                "sensitive": null,
+                "visibility": "private",
+                "idempotency": null,
+                "scheduled_at": null,
+                "spoiler_text": null,
+                "application_id": 596551,
+                "in_reply_to_id": null
+            },
+            "media_attachments": []
+        }"#;
+        let response: NewStatusResponse =
+            serde_json::from_str(json).expect("Couldn't deserialize scheduled status");
+        let scheduled = match response {
+            NewStatusResponse::Scheduled(scheduled) => scheduled,
+            NewStatusResponse::Status(_) => panic!("expected a scheduled status"),
+        };
+        assert_eq!(scheduled.id, "3221");
+        assert_eq!(scheduled.params.text, "test content");
+        assert_eq!(scheduled.params.visibility, Some(Visibility::Private));
+        assert_eq!(scheduled.params.application_id, Some(596551));
+        assert_eq!(scheduled.params.poll.map(|poll| poll.expires_in), Some(300));
+    }
+}
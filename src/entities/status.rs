@@ -2,12 +2,15 @@
 
 use super::prelude::*;
 use crate::{
-    entities::{card::Card, poll::Poll},
+    entities::{card::Card, filter::FilterResult, poll::Poll},
     status_builder::Visibility,
 };
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
+// `Application` used to live here, keep it importable from this module
+pub use super::application::Application;
+
 /// A status from the instance.
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Status {
@@ -76,6 +79,56 @@ pub struct Status {
     pub bookmarked: Option<bool>,
     /// Whether this is the pinned status for the account that posted it.
     pub pinned: Option<bool>,
+    /// The time the status was last edited, if it has been edited.
+    pub edited_at: Option<DateTime<Utc>>,
+    /// The filters of the authenticated user that matched this status.
+    pub filtered: Option<Vec<FilterResult>>,
+}
+
+/// A revision of a status, as returned by the edit history.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StatusEdit {
+    /// Body of the status at this revision; this will contain HTML.
+    pub content: String,
+    /// Content warning of the status at this revision.
+    pub spoiler_text: String,
+    /// Whether media attachments were hidden by default at this revision.
+    pub sensitive: bool,
+    /// The time this revision was published.
+    pub created_at: DateTime<Utc>,
+    /// The account that published this revision.
+    pub account: Account,
+    /// The poll attached to the status at this revision.
+    pub poll: Option<StatusEditPoll>,
+    /// The media attachments of the status at this revision.
+    pub media_attachments: Vec<Attachment>,
+    /// Custom emoji used in this revision.
+    pub emojis: Vec<Emoji>,
+}
+
+/// The poll of a status revision.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StatusEditPoll {
+    /// The options of the poll at this revision.
+    pub options: Vec<StatusEditPollOption>,
+}
+
+/// An option of the poll of a status revision.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StatusEditPollOption {
+    /// The text of the option.
+    pub title: String,
+}
+
+/// The plain-text source of a status, used for editing.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StatusSource {
+    /// ID of the status.
+    pub id: String,
+    /// The plain text used to compose the status.
+    pub text: String,
+    /// The plain text used to compose the status's content warning.
+    pub spoiler_text: String,
 }
 
 /// A mention of another user.
@@ -111,24 +164,20 @@ pub struct Tag {
     pub url: String,
     /// Usage statistics for given days.
     pub history: Option<Vec<History>>,
+    /// Whether the authenticated user is following this tag.
+    pub following: Option<bool>,
 }
 
 /// Represents daily usage history of a hashtag.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
 pub struct History {
     /// UNIX timestamp on midnight of the given day.
-    day: String,
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub day: u64,
     /// the counted usage of the tag within that day.
-    uses: String,
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub uses: u64,
     /// the total of accounts using the tag within that day.
-    accounts: String,
-}
-
-/// Application details.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub struct Application {
-    /// Name of the application.
-    pub name: String,
-    /// Homepage URL of the application.
-    pub website: Option<String>,
+    #[serde(deserialize_with = "super::string_or_u64")]
+    pub accounts: u64,
 }
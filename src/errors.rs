@@ -28,6 +28,9 @@ pub enum Error {
     /// Error from the Mastodon API. This typically means something went
     /// wrong with your authentication or data.
     Api(ApiError),
+    /// Error from the OAuth endpoints, e.g. an expired authorization code or
+    /// a user denying access to the app.
+    OAuth(OAuthError),
     /// Error deserialising to json. Typically represents a breaking change in
     /// the Mastodon API
     Serde(SerdeError),
@@ -49,6 +52,14 @@ pub enum Error {
     Client(StatusCode),
     /// Generic server error.
     Server(StatusCode),
+    /// The server refused a write because the resource was changed by another
+    /// client in the meantime (HTTP 409). Fetch the resource and retry.
+    Conflict,
+    /// The given `user@domain` handle could not be resolved to an account.
+    AccountNotFound(String),
+    /// The `state` parameter returned with an authorization code didn't match
+    /// the one sent to the authorization page.
+    StateMismatch,
     /// MastodonBuilder & AppBuilder error
     MissingField(&'static str),
     #[cfg(feature = "toml")]
@@ -88,6 +99,7 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         Some(match *self {
             Error::Api(ref e) => e,
+            Error::OAuth(ref e) => e,
             Error::Serde(ref e) => e,
             Error::UrlEncoded(ref e) => e,
             Error::Http(ref e) => e,
@@ -105,6 +117,9 @@ impl error::Error for Error {
             Error::WebSocket(ref e) => e,
 
             Error::Client(..) | Error::Server(..) => return None,
+            Error::Conflict => return None,
+            Error::AccountNotFound(..) => return None,
+            Error::StateMismatch => return None,
             Error::ClientIdRequired => return None,
             Error::ClientSecretRequired => return None,
             Error::AccessTokenRequired => return None,
@@ -135,6 +150,24 @@ impl fmt::Display for ApiError {
 
 impl error::Error for ApiError {}
 
+/// Error returned from the OAuth endpoints, as described in [RFC 6749,
+/// section 5.2](https://tools.ietf.org/html/rfc6749#section-5.2).
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct OAuthError {
+    /// The error code, e.g. `invalid_grant` or `access_denied`.
+    pub error: String,
+    /// The human-readable description of the error.
+    pub error_description: Option<String>,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl error::Error for OAuthError {}
+
 macro_rules! from {
     ($($(#[$met:meta])* $typ:ident, $variant:ident,)*) => {
         $(
@@ -156,6 +189,7 @@ from! {
     UrlEncodedError, UrlEncoded,
     UrlError, Url,
     ApiError, Api,
+    OAuthError, OAuth,
     #[cfg(feature = "toml")] TomlSerError, TomlSer,
     #[cfg(feature = "toml")] TomlDeError, TomlDe,
     HeaderStrError, HeaderStrError,
@@ -241,6 +275,17 @@ mod tests {
         assert_is!(err, Error::Api(..));
     }
 
+    #[test]
+    fn from_oauth_error() {
+        let err: OAuthError = serde_json::from_str(
+            r#"{"error":"invalid_grant","error_description":"The provided authorization grant is invalid"}"#,
+        )
+        .expect("Couldn't deserialize");
+        assert_eq!(err.error, "invalid_grant");
+        let err: Error = Error::from(err);
+        assert_is!(err, Error::OAuth(..));
+    }
+
     #[cfg(feature = "toml")]
     #[test]
     fn from_toml_ser_error() {
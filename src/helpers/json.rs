@@ -77,6 +77,7 @@ pub fn to_file_with_options<P: AsRef<Path>>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scopes::Scopes;
     use std::{fs::OpenOptions, io::Cursor};
     use tempfile::{tempdir, NamedTempFile};
 
@@ -103,6 +104,7 @@ mod tests {
                 client_secret: "0987dcba".into(),
                 redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
                 token: "fedc5678".into(),
+                scopes: None,
             }
         );
     }
@@ -118,6 +120,7 @@ mod tests {
                 client_secret: "0987dcba".into(),
                 redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
                 token: "fedc5678".into(),
+                scopes: None,
             }
         );
     }
@@ -134,6 +137,7 @@ mod tests {
                 client_secret: "0987dcba".into(),
                 redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
                 token: "fedc5678".into(),
+                scopes: None,
             }
         );
     }
@@ -150,6 +154,7 @@ mod tests {
                 client_secret: "0987dcba".into(),
                 redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
                 token: "fedc5678".into(),
+                scopes: None,
             }
         );
     }
@@ -161,6 +166,7 @@ mod tests {
             client_secret: "0987dcba".into(),
             redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
             token: "fedc5678".into(),
+            scopes: None,
         };
         let s = to_string(&data).expect("Couldn't serialize Data");
         let desered = from_str(&s).expect("Couldn't deserialize Data");
@@ -174,6 +180,7 @@ mod tests {
             client_secret: "0987dcba".into(),
             redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
             token: "fedc5678".into(),
+            scopes: None,
         };
         let v = to_vec(&data).expect("Couldn't write to vec");
         let desered = from_slice(&v).expect("Couldn't deserialize data");
@@ -187,6 +194,7 @@ mod tests {
             client_secret: "0987dcba".into(),
             redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
             token: "fedc5678".into(),
+            scopes: None,
         };
         let mut buffer = Vec::new();
         to_writer(&data, &mut buffer).expect("Couldn't write to writer");
@@ -202,6 +210,7 @@ mod tests {
             client_secret: "0987dcba".into(),
             redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
             token: "fedc5678".into(),
+            scopes: None,
         };
         let tempdir = tempdir().expect("Couldn't create tempdir");
         let filename = tempdir.path().join("mastodon-data.json");
@@ -217,6 +226,7 @@ mod tests {
             client_secret: "0987dcba".into(),
             redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
             token: "fedc5678".into(),
+            scopes: None,
         };
         let file = NamedTempFile::new().expect("Couldn't create tempfile");
         let mut options = OpenOptions::new();
@@ -225,4 +235,37 @@ mod tests {
         let desered = from_file(file.path()).expect("Couldn't deserialize Data");
         assert_eq!(data, desered);
     }
+    #[test]
+    fn test_scopes_round_trip() {
+        let data = Data {
+            base: "https://example.com".into(),
+            client_id: "adbc01234".into(),
+            client_secret: "0987dcba".into(),
+            redirect: "urn:ietf:wg:oauth:2.0:oob".into(),
+            token: "fedc5678".into(),
+            scopes: Some(Scopes::read_all() | Scopes::follow()),
+        };
+        let s = to_string(&data).expect("Couldn't serialize Data");
+        assert!(s.contains(r#""scopes": "read follow""#));
+        let desered = from_str(&s).expect("Couldn't deserialize Data");
+        assert_eq!(data, desered);
+    }
+    #[test]
+    fn test_from_str_with_scopes() {
+        let desered = from_str(
+            r#"{
+            "base": "https://example.com",
+            "client_id": "adbc01234",
+            "client_secret": "0987dcba",
+            "redirect": "urn:ietf:wg:oauth:2.0:oob",
+            "token": "fedc5678",
+            "scopes": "read write"
+        }"#,
+        )
+        .expect("Couldn't deserialize Data");
+        assert_eq!(
+            desered.scopes,
+            Some(Scopes::read_all() | Scopes::write_all())
+        );
+    }
 }
@@ -0,0 +1,404 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::{Duration, Instant},
+};
+
+use url::Url;
+
+use crate::{
+    errors::{Error, OAuthError, Result},
+    registration::{verify_state, Registered, Registration},
+    Mastodon,
+};
+
+const SUCCESS_PAGE: &str =
+    "<html><body>Authorization complete, you can close this window.</body></html>";
+const FAILURE_PAGE: &str = "<html><body>Authorization failed, please try again.</body></html>";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A server listening on a `127.0.0.1` port for the browser to be redirected
+/// back from the instance's authorization page
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate elefren;
+/// # fn main() -> elefren::Result<()> {
+/// use elefren::{helpers::loopback::LoopbackServer, prelude::*};
+///
+/// let server = LoopbackServer::bind()?;
+/// let registered = Registration::new("https://mastodon.social")
+///     .client_name("elefren_test")
+///     .redirect_uris(server.redirect_uri().to_string())
+///     .build()?;
+/// let code = server.authorize(&registered, |url| {
+///     println!("Click this link to authorize: {}", url);
+///     Ok(())
+/// })?;
+/// let mastodon = registered.complete(&code)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LoopbackServer {
+    listener: TcpListener,
+    redirect_uri: String,
+    timeout: Duration,
+}
+
+impl LoopbackServer {
+    /// Listen on a free port picked by the operating system
+    pub fn bind() -> Result<LoopbackServer> {
+        LoopbackServer::bind_port(0)
+    }
+
+    /// Listen on the given port, for apps that were registered with a fixed
+    /// `http://127.0.0.1:port/` redirect uri
+    pub fn bind_port(port: u16) -> Result<LoopbackServer> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let port = listener.local_addr()?.port();
+        Ok(LoopbackServer {
+            listener,
+            redirect_uri: format!("http://127.0.0.1:{}/", port),
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    /// Give up waiting for the redirect after `timeout`, instead of the
+    /// default of five minutes
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The redirect uri to register the app with
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Hands the authorization url of `registered` to `open`, which should
+    /// open it in a browser or show it to the user, and waits for the
+    /// authorization code to come back.
+    ///
+    /// The code is only accepted if the redirect carries the same `state`
+    /// parameter as the authorization url.
+    pub fn authorize<F>(&self, registered: &Registered, open: F) -> Result<String>
+    where
+        F: FnOnce(&str) -> Result<()>,
+    {
+        open(&registered.authorize_url()?)?;
+        self.receive_code(registered.state())
+    }
+
+    /// Waits for the redirect from the authorization page and returns the
+    /// authorization code, if its `state` parameter matches `state`
+    ///
+    /// Other requests, like the browser asking for a favicon, are answered
+    /// with a 404. Returns an `Error::Io` with `io::ErrorKind::TimedOut` if
+    /// no redirect arrives within the timeout.
+    pub fn receive_code(&self, state: &str) -> Result<String> {
+        let deadline = Instant::now() + self.timeout;
+        self.listener.set_nonblocking(true)?;
+        loop {
+            let mut stream = match self.listener.accept() {
+                Ok((stream, _)) => stream,
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "timed out waiting for the authorization redirect",
+                        )
+                        .into());
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                    continue;
+                },
+                Err(e) => return Err(e.into()),
+            };
+            stream.set_nonblocking(false)?;
+            stream.set_read_timeout(Some(
+                deadline
+                    .saturating_duration_since(Instant::now())
+                    .max(POLL_INTERVAL),
+            ))?;
+            let params = match read_callback(&stream) {
+                Ok(Some(params)) => params,
+                Ok(None) => {
+                    respond(&mut stream, "404 Not Found", "")?;
+                    continue;
+                },
+                // A client that hangs up or stalls shouldn't end the flow
+                Err(Error::Io(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            let result = check_callback(&params, state);
+            let page = if result.is_ok() {
+                SUCCESS_PAGE
+            } else {
+                FAILURE_PAGE
+            };
+            respond(&mut stream, "200 OK", page)?;
+            return result;
+        }
+    }
+}
+
+/// Finishes the authentication process for the given `Registration`, by
+/// registering the app with a loopback redirect uri and waiting for the
+/// browser to be redirected back, instead of having the user paste the code.
+///
+/// `open` is given the authorization url, and should open it in a browser or
+/// show it to the user.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate elefren;
+/// # fn main() -> elefren::Result<()> {
+/// use elefren::{helpers::loopback, prelude::*};
+///
+/// let mut registration = Registration::new("https://mastodon.social");
+/// registration.client_name("elefren_test");
+/// let mastodon = loopback::authenticate(&mut registration, |url| {
+///     println!("Click this link to authorize: {}", url);
+///     Ok(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn authenticate<F>(registration: &mut Registration, open: F) -> Result<Mastodon>
+where
+    F: FnOnce(&str) -> Result<()>,
+{
+    let server = LoopbackServer::bind()?;
+    let registered = registration
+        .redirect_uris(server.redirect_uri().to_string())
+        .build()?;
+    let code = server.authorize(&registered, open)?;
+    registered.complete(&code)
+}
+
+/// Reads the request line and headers, and returns the query parameters if
+/// this is the redirect back from the authorization page, carrying either a
+/// `code` or an `error`
+fn read_callback(stream: &TcpStream) -> Result<Option<Vec<(String, String)>>> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let target = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => target,
+        _ => return Ok(None),
+    };
+    let url = Url::parse("http://127.0.0.1/")?.join(target)?;
+    if url.path() != "/" {
+        return Ok(None);
+    }
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if !params
+        .iter()
+        .any(|(key, _)| key == "code" || key == "error")
+    {
+        return Ok(None);
+    }
+    Ok(Some(params))
+}
+
+fn check_callback(params: &[(String, String)], state: &str) -> Result<String> {
+    let param = |name: &str| {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    verify_state(state, &param("state").unwrap_or_default())?;
+    if let Some(error) = param("error") {
+        return Err(Error::OAuth(OAuthError {
+            error,
+            error_description: param("error_description"),
+        }));
+    }
+    param("code").ok_or(Error::MissingField("code"))
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: \
+         {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scopes::Scopes;
+    use std::io::Read;
+
+    // Stands in for the instance's authorization page: reads the redirect uri
+    // and state from the authorization url, and redirects the "browser" back
+    // with the given extra parameters.
+    fn fake_authorization_server(
+        authorize_url: &str,
+        params: &[(&str, &str)],
+        state: Option<&str>,
+    ) {
+        let url = Url::parse(authorize_url).expect("Couldn't parse url");
+        let query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        let get = |name: &str| {
+            query
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .expect("Missing query parameter")
+        };
+        let redirect_uri = get("redirect_uri");
+        let state = state.map(String::from).unwrap_or_else(|| get("state"));
+
+        let mut redirect = Url::parse(&redirect_uri).expect("Couldn't parse redirect uri");
+        redirect.query_pairs_mut().extend_pairs(params);
+        redirect.query_pairs_mut().append_pair("state", &state);
+
+        thread::spawn(move || {
+            // browsers ask for a favicon too, which should be ignored, as
+            // should requests without a code or an error
+            assert!(get_url("/favicon.ico", &redirect).starts_with("HTTP/1.1 404"));
+            assert!(get_url("/", &redirect).starts_with("HTTP/1.1 404"));
+            let target = format!("/?{}", redirect.query().unwrap_or(""));
+            get_url(&target, &redirect);
+        });
+    }
+
+    fn get_url(target: &str, redirect: &Url) -> String {
+        let addr = (
+            redirect.host_str().expect("No host"),
+            redirect.port().expect("No port"),
+        );
+        let mut stream = TcpStream::connect(addr).expect("Couldn't connect");
+        write!(stream, "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", target)
+            .expect("Couldn't send request");
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .expect("Couldn't read response");
+        response
+    }
+
+    fn registered(server: &LoopbackServer) -> Registered {
+        Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            server.redirect_uri(),
+            Scopes::read_all(),
+            false,
+        )
+    }
+
+    // Fails the test instead of hanging it if the fake authorization server
+    // panics
+    fn server() -> LoopbackServer {
+        LoopbackServer::bind()
+            .expect("Couldn't bind")
+            .with_timeout(Duration::from_secs(10))
+    }
+
+    #[test]
+    fn test_bind() {
+        let server = server();
+        assert!(server.redirect_uri().starts_with("http://127.0.0.1:"));
+        assert!(server.redirect_uri().ends_with('/'));
+    }
+
+    #[test]
+    fn test_authorize() {
+        let server = server();
+        let code = server
+            .authorize(&registered(&server), |url| {
+                fake_authorization_server(url, &[("code", "the-code")], None);
+                Ok(())
+            })
+            .expect("Couldn't authorize");
+        assert_eq!(code, "the-code");
+    }
+
+    #[test]
+    fn test_authorize_state_mismatch() {
+        let server = server();
+        let err = server
+            .authorize(&registered(&server), |url| {
+                fake_authorization_server(url, &[("code", "the-code")], Some("forged"));
+                Ok(())
+            })
+            .expect_err("Accepted a forged state");
+        assert!(matches!(err, Error::StateMismatch));
+    }
+
+    #[test]
+    fn test_authorize_denied() {
+        let server = server();
+        let err = server
+            .authorize(&registered(&server), |url| {
+                fake_authorization_server(
+                    url,
+                    &[
+                        ("error", "access_denied"),
+                        ("error_description", "The user denied the request"),
+                    ],
+                    None,
+                );
+                Ok(())
+            })
+            .expect_err("Accepted a denied authorization");
+        match err {
+            Error::OAuth(OAuthError {
+                error,
+                error_description,
+            }) => {
+                assert_eq!(error, "access_denied");
+                assert_eq!(
+                    error_description.as_deref(),
+                    Some("The user denied the request")
+                );
+            },
+            other => panic!("expected an oauth error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_authorize_timeout() {
+        let server = LoopbackServer::bind()
+            .expect("Couldn't bind")
+            .with_timeout(Duration::from_millis(200));
+        let err = server
+            .authorize(&registered(&server), |_| Ok(()))
+            .expect_err("Didn't time out");
+        match err {
+            Error::Io(e) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_callback_missing_code() {
+        let params = vec![("state".to_string(), "xyz".to_string())];
+        let err = check_callback(&params, "xyz").expect_err("Accepted a missing code");
+        assert!(matches!(err, Error::MissingField("code")));
+    }
+}
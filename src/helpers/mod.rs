@@ -36,3 +36,7 @@ pub mod env;
 
 /// Helpers for working with the command line
 pub mod cli;
+
+/// Helpers for completing the authorization flow through a redirect to a
+/// local server
+pub mod loopback;
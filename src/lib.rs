@@ -18,7 +18,7 @@
 //! println!(
 //!     "{:?}",
 //!     mastodon
-//!         .get_home_timeline()?
+//!         .get_home_timeline(None)?
 //!         .items_iter()
 //!         .take(100)
 //!         .collect::<Vec<_>>()
@@ -43,6 +43,7 @@
 //! #   client_secret: "".into(),
 //! #   redirect: "".into(),
 //! #   token: "".into(),
+//! #   scopes: None,
 //! # };
 //! let client = Mastodon::from(data);
 //! for event in client.streaming_user()? {
@@ -51,6 +52,7 @@
 //!         Event::Notification(ref notification) => { /* .. */ },
 //!         Event::Delete(ref id) => { /* .. */ },
 //!         Event::FiltersChanged => { /* .. */ },
+//!         Event::Conversation(ref conversation) => { /* .. */ },
 //!     }
 //! }
 //! # Ok(())
@@ -73,27 +75,45 @@
 
 use std::{borrow::Cow, io::BufRead, ops};
 
+use chrono::prelude::*;
 use reqwest::{Client, RequestBuilder, Response};
 use tungstenite::client::AutoStream;
 
-use crate::{entities::prelude::*, page::Page};
+use crate::{entities::prelude::*, page::Page, status_builder::Visibility};
 
 pub use isolang::Language;
 
 pub use crate::{
     data::Data,
-    errors::{ApiError, Error, Result},
+    errors::{ApiError, Error, OAuthError, Result},
     mastodon_client::{MastodonClient, MastodonUnauthenticated},
     media_builder::MediaBuilder,
     registration::Registration,
     requests::{
         AddFilterRequest,
         AddPushRequest,
+        AdminAccountsRequest,
+        AdminActionRequest,
+        FilterRequest,
+        FollowOptions,
+        ListRequest,
+        MuteOptions,
+        NotificationsRequest,
+        ReportRequest,
         StatusesRequest,
+        TimelineRequest,
         UpdateCredsRequest,
         UpdatePushRequest,
     },
-    status_builder::{NewStatus, StatusBuilder},
+    status_builder::{
+        EditStatus,
+        EditStatusBuilder,
+        MediaAttribute,
+        NewPoll,
+        NewStatus,
+        PollBuilder,
+        StatusBuilder,
+    },
 };
 
 /// Registering your App
@@ -153,6 +173,68 @@ impl Mastodon {
         format!("{}{}", self.base, url)
     }
 
+    fn timeline(&self, path: &str, request: Option<TimelineRequest>) -> Result<Page<Status>> {
+        let mut url = self.route(path);
+
+        if let Some(request) = request {
+            url = format!("{}{}", url, request.to_querystring()?);
+        }
+
+        let response = self.send_blocking(self.client.get(&url))?;
+
+        Page::new(self, response)
+    }
+
+    // Resolve a handle that the server doesn't know about yet, by asking the
+    // account's home server for its canonical handle and then having our
+    // server fetch the account.
+    fn resolve_account(&self, acct: &str) -> Result<Account> {
+        let handle = match parse_handle(acct)? {
+            (username, Some(domain)) => self.webfinger(webfinger_url(username, domain)?, acct)?,
+            (username, None) => username.to_string(),
+        };
+
+        // Local accounts are listed without their domain
+        let base: url::Url = self.base.parse()?;
+        let local_suffix = base
+            .host_str()
+            .map(|host| format!("@{}", host.to_ascii_lowercase()));
+        let local = match local_suffix {
+            Some(ref suffix) if handle.to_ascii_lowercase().ends_with(suffix) => {
+                &handle[..handle.len() - suffix.len()]
+            },
+            _ => &handle[..],
+        };
+
+        self.search_v2(&handle, true)?
+            .accounts
+            .into_iter()
+            .find(|account| account.acct.eq_ignore_ascii_case(local))
+            .ok_or_else(|| Error::AccountNotFound(acct.to_string()))
+    }
+
+    // Asks the account's own server for its canonical `user@domain` handle
+    fn webfinger(&self, url: url::Url, acct: &str) -> Result<String> {
+        // Not sent through `send_blocking`: the access token is only meant for
+        // our own server.
+        let request = self.client.get(url).build()?;
+        let runtime = tokio::runtime::Handle::current();
+        let response = runtime.block_on(self.client.execute(request))?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::GONE {
+            return Err(Error::AccountNotFound(acct.to_string()));
+        } else if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        let resource: WebfingerResource = deserialise_blocking(response)?;
+        Ok(resource.subject.trim_start_matches("acct:").to_string())
+    }
+
     pub(crate) fn send_blocking(&self, req: RequestBuilder) -> Result<Response> {
         let request = req.bearer_auth(&self.token).build()?;
         let handle = tokio::runtime::Handle::current();
@@ -177,18 +259,19 @@ impl MastodonClient for Mastodon {
 
     paged_routes! {
         (get) favourites: "favourites" => Status,
+        (get) bookmarks: "bookmarks" => Status,
         (get) blocks: "blocks" => Account,
         (get) domain_blocks: "domain_blocks" => String,
         (get) follow_requests: "follow_requests" => Account,
-        (get) get_home_timeline: "timelines/home" => Status,
-        (get) get_local_timeline: "timelines/public?local=true" => Status,
-        (get) get_federated_timeline: "timelines/public?local=false" => Status,
         (get) get_emojis: "custom_emojis" => Emoji,
         (get) mutes: "mutes" => Account,
-        (get) notifications: "notifications" => Notification,
         (get) reports: "reports" => Report,
         (get (q: &'a str, #[serde(skip_serializing_if = "Option::is_none")] limit: Option<u64>, following: bool,)) search_accounts: "accounts/search" => Account,
         (get) get_endorsements: "endorsements" => Account,
+        (get) scheduled_statuses: "scheduled_statuses" => ScheduledStatus,
+        (get) conversations: "conversations" => Conversation,
+        (get) followed_tags: "followed_tags" => Tag,
+        (get) admin_accounts: "admin/accounts" => AdminAccount,
     }
 
     paged_routes_with_id! {
@@ -196,13 +279,19 @@ impl MastodonClient for Mastodon {
         (get) following: "accounts/{}/following" => Account,
         (get) reblogged_by: "statuses/{}/reblogged_by" => Account,
         (get) favourited_by: "statuses/{}/favourited_by" => Account,
+        (get) list_accounts: "lists/{}/accounts" => Account,
     }
 
     route! {
         (delete (domain: String,)) unblock_domain: "domain_blocks" => Empty,
         (get) instance: "instance" => Instance,
+        (get) get_preferences: "preferences" => Preferences,
+        (get) instance_rules: "instance/rules" => Vec<Rule>,
+        (get) peers: "instance/peers" => Vec<String>,
+        (get) activity: "instance/activity" => Vec<Activity>,
+        (get) extended_description: "instance/extended_description" => ExtendedDescription,
         (get) verify_credentials: "accounts/verify_credentials" => Account,
-        (post (account_id: &str, status_ids: Vec<&str>, comment: String,)) report: "reports" => Report,
+        (get) verify_app_credentials: "apps/verify_credentials" => Application,
         (post (domain: String,)) block_domain: "domain_blocks" => Empty,
         (post (id: &str,)) authorize_follow_request: "accounts/follow_requests/authorize" => Empty,
         (post (id: &str,)) reject_follow_request: "accounts/follow_requests/reject" => Empty,
@@ -214,34 +303,281 @@ impl MastodonClient for Mastodon {
         (delete) delete_push_subscription: "push/subscription" => Empty,
         (get) get_filters: "filters" => Vec<Filter>,
         (get) get_follow_suggestions: "suggestions" => Vec<Account>,
+        (get) get_lists: "lists" => Vec<List>,
+        (get (with_dismissed: bool,)) get_announcements: "announcements" => Vec<Announcement>,
+        (get) get_featured_tags: "featured_tags" => Vec<FeaturedTag>,
+        (post (name: &str,)) feature_tag: "featured_tags" => FeaturedTag,
+        (get) get_featured_tag_suggestions: "featured_tags/suggestions" => Vec<Tag>,
     }
 
     route_v2! {
         (get (q: &'a str, resolve: bool,)) search_v2: "search" => SearchResultV2,
+        (get ()) instance_v2: "instance" => InstanceV2,
+        (get ()) get_filters_v2: "filters" => Vec<FilterV2>,
+    }
+
+    route_v2_id! {
+        (get) get_filter_v2: "filters/{}" => FilterV2,
+        (delete) delete_filter_v2: "filters/{}" => Empty,
+        (get) get_filter_keywords: "filters/{}/keywords" => Vec<FilterKeyword>,
+        (get) get_filter_keyword: "filters/keywords/{}" => FilterKeyword,
+        (delete) delete_filter_keyword: "filters/keywords/{}" => Empty,
+        (get) get_filter_statuses: "filters/{}/statuses" => Vec<FilterStatus>,
+        (get) get_filter_status: "filters/statuses/{}" => FilterStatus,
+        (delete) delete_filter_status: "filters/statuses/{}" => Empty,
     }
 
     route_id! {
         (get) get_account: "accounts/{}" => Account,
-        (post) follow: "accounts/{}/follow" => Relationship,
         (post) unfollow: "accounts/{}/unfollow" => Relationship,
         (post) block: "accounts/{}/block" => Relationship,
         (post) unblock: "accounts/{}/unblock" => Relationship,
-        (get) mute: "accounts/{}/mute" => Relationship,
-        (get) unmute: "accounts/{}/unmute" => Relationship,
+        (post) unmute: "accounts/{}/unmute" => Relationship,
         (get) get_notification: "notifications/{}" => Notification,
         (get) get_status: "statuses/{}" => Status,
         (get) get_context: "statuses/{}/context" => Context,
         (get) get_card: "statuses/{}/card" => Card,
         (post) reblog: "statuses/{}/reblog" => Status,
         (post) unreblog: "statuses/{}/unreblog" => Status,
+        (post) pin_status: "statuses/{}/pin" => Status,
+        (post) unpin_status: "statuses/{}/unpin" => Status,
+        (post) mute_status: "statuses/{}/mute" => Status,
+        (post) unmute_status: "statuses/{}/unmute" => Status,
         (post) favourite: "statuses/{}/favourite" => Status,
         (post) unfavourite: "statuses/{}/unfavourite" => Status,
+        (post) bookmark: "statuses/{}/bookmark" => Status,
+        (post) unbookmark: "statuses/{}/unbookmark" => Status,
         (delete) delete_status: "statuses/{}" => Empty,
         (get) get_filter: "filters/{}" => Filter,
         (delete) delete_filter: "filters/{}" => Empty,
         (delete) delete_from_suggestions: "suggestions/{}" => Empty,
         (post) endorse_user: "accounts/{}/pin" => Relationship,
         (post) unendorse_user: "accounts/{}/unpin" => Relationship,
+        (get) get_poll: "polls/{}" => Poll,
+        (get) get_scheduled_status: "scheduled_statuses/{}" => ScheduledStatus,
+        (delete) cancel_scheduled_status: "scheduled_statuses/{}" => Empty,
+        (post) mark_conversation_read: "conversations/{}/read" => Conversation,
+        (delete) delete_conversation: "conversations/{}" => Empty,
+        (post) dismiss_announcement: "announcements/{}/dismiss" => Empty,
+        (delete) unfeature_tag: "featured_tags/{}" => Empty,
+        (get) get_account_featured_tags: "accounts/{}/featured_tags" => Vec<FeaturedTag>,
+        (get) get_tag: "tags/{}" => Tag,
+        (post) follow_tag: "tags/{}/follow" => Tag,
+        (post) unfollow_tag: "tags/{}/unfollow" => Tag,
+        (get) status_history: "statuses/{}/history" => Vec<StatusEdit>,
+        (get) status_source: "statuses/{}/source" => StatusSource,
+        (get) get_list: "lists/{}" => List,
+        (delete) delete_list: "lists/{}" => Empty,
+        (get) get_account_lists: "accounts/{}/lists" => Vec<List>,
+        (get) admin_get_account: "admin/accounts/{}" => AdminAccount,
+        (post) admin_approve_account: "admin/accounts/{}/approve" => AdminAccount,
+        (post) admin_reject_account: "admin/accounts/{}/reject" => AdminAccount,
+        (post) admin_enable_account: "admin/accounts/{}/enable" => AdminAccount,
+        (post) admin_unsilence_account: "admin/accounts/{}/unsilence" => AdminAccount,
+        (post) admin_unsuspend_account: "admin/accounts/{}/unsuspend" => AdminAccount,
+        (delete) admin_delete_account: "admin/accounts/{}" => AdminAccount,
+    }
+
+    /// Follow an account, optionally choosing whether to show its reblogs,
+    /// be notified of its statuses or only see statuses in some languages.
+    fn follow<O>(&self, id: &str, options: O) -> Result<Relationship>
+    where
+        O: Into<Option<FollowOptions>>,
+    {
+        let url = self.route(&format!("/api/v1/accounts/{}/follow", id));
+        let options = options.into().unwrap_or_default();
+        let response = self.send_blocking(self.client.post(&url).json(&options))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Mute an account, optionally keeping its notifications or only for a
+    /// limited time.
+    fn mute<O>(&self, id: &str, options: O) -> Result<Relationship>
+    where
+        O: Into<Option<MuteOptions>>,
+    {
+        let url = self.route(&format!("/api/v1/accounts/{}/mute", id));
+        let options = options.into().unwrap_or_default();
+        let response = self.send_blocking(self.client.post(&url).json(&options))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Report an account, and optionally some of its statuses, to the
+    /// moderators.
+    fn report(&self, request: &ReportRequest) -> Result<Report> {
+        let url = self.route("/api/v1/reports");
+        let response = self.send_blocking(self.client.post(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// POST /oauth/revoke
+    fn revoke_token(&self) -> Result<Empty> {
+        let url = self.route("/oauth/revoke");
+        let form = [
+            ("client_id", &self.client_id[..]),
+            ("client_secret", &self.client_secret[..]),
+            ("token", &self.token[..]),
+        ];
+        let response = self.send_blocking(self.client.post(&url).form(&form))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// POST /api/v2/filters
+    fn add_filter_v2(&self, request: &FilterRequest) -> Result<FilterV2> {
+        let url = self.route("/api/v2/filters");
+        let response = self.send_blocking(self.client.post(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// PUT /api/v2/filters/:id
+    fn update_filter_v2(&self, id: &str, request: &FilterRequest) -> Result<FilterV2> {
+        let url = self.route(&format!("/api/v2/filters/{}", id));
+        let response = self.send_blocking(self.client.put(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// POST /api/v2/filters/:id/keywords
+    fn add_filter_keyword(
+        &self,
+        filter_id: &str,
+        keyword: &str,
+        whole_word: bool,
+    ) -> Result<FilterKeyword> {
+        let url = self.route(&format!("/api/v2/filters/{}/keywords", filter_id));
+        let form_data = serde_json::json!({ "keyword": keyword, "whole_word": whole_word });
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// PUT /api/v2/filters/keywords/:id
+    fn update_filter_keyword(
+        &self,
+        id: &str,
+        keyword: &str,
+        whole_word: bool,
+    ) -> Result<FilterKeyword> {
+        let url = self.route(&format!("/api/v2/filters/keywords/{}", id));
+        let form_data = serde_json::json!({ "keyword": keyword, "whole_word": whole_word });
+        let response = self.send_blocking(self.client.put(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// POST /api/v2/filters/:id/statuses
+    fn add_filter_status(&self, filter_id: &str, status_id: &str) -> Result<FilterStatus> {
+        let url = self.route(&format!("/api/v2/filters/{}/statuses", filter_id));
+        let form_data = serde_json::json!({ "status_id": status_id });
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// GET /api/v2/admin/accounts
+    fn admin_accounts_v2(&self, request: &AdminAccountsRequest) -> Result<Page<AdminAccount>> {
+        let url = format!(
+            "{}/api/v2/admin/accounts{}",
+            self.base,
+            request.to_querystring()?
+        );
+
+        let response = self.send_blocking(self.client.get(&url))?;
+
+        Page::new(self, response)
+    }
+
+    /// POST /api/v1/admin/accounts/:id/action
+    fn admin_account_action(&self, id: &str, request: &AdminActionRequest) -> Result<Empty> {
+        let url = self.route(&format!("/api/v1/admin/accounts/{}/action", id));
+        let response = self.send_blocking(self.client.post(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
     }
 
     fn add_filter(&self, request: &mut AddFilterRequest) -> Result<Filter> {
@@ -275,6 +611,222 @@ impl MastodonClient for Mastodon {
         deserialise_blocking(response)
     }
 
+    fn create_list(&self, request: &ListRequest) -> Result<List> {
+        let url = self.route("/api/v1/lists");
+        let response = self.send_blocking(self.client.post(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn update_list(&self, id: &str, request: &ListRequest) -> Result<List> {
+        let url = self.route(&format!("/api/v1/lists/{}", id));
+        let response = self.send_blocking(self.client.put(&url).json(&request))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn add_to_list(&self, id: &str, account_ids: &[&str]) -> Result<Empty> {
+        let url = self.route(&format!("/api/v1/lists/{}/accounts", id));
+        let form_data = serde_json::json!({ "account_ids": account_ids });
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn remove_from_list(&self, id: &str, account_ids: &[&str]) -> Result<Empty> {
+        let url = self.route(&format!("/api/v1/lists/{}/accounts", id));
+        let form_data = serde_json::json!({ "account_ids": account_ids });
+        let response = self.send_blocking(self.client.delete(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn vote(&self, id: &str, choices: &[u64]) -> Result<Poll> {
+        let url = self.route(&format!("/api/v1/polls/{}/votes", id));
+        let form_data = serde_json::json!({ "choices": choices });
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn reschedule_status(&self, id: &str, scheduled_at: DateTime<Utc>) -> Result<ScheduledStatus> {
+        let url = self.route(&format!("/api/v1/scheduled_statuses/{}", id));
+        let form_data = serde_json::json!({ "scheduled_at": scheduled_at });
+        let response = self.send_blocking(self.client.put(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Get the saved read positions for the given timelines
+    fn get_markers(&self, timelines: &[MarkerTimeline]) -> Result<Markers> {
+        let mut url: url::Url = self.route("/api/v1/markers").parse()?;
+        for timeline in timelines {
+            url.query_pairs_mut()
+                .append_pair("timeline[]", timeline.as_str());
+        }
+        let response = self.send_blocking(self.client.get(url))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Find the account for a `user@domain` handle (or a `user` handle for
+    /// local accounts).
+    ///
+    /// If our server doesn't know the account yet, it is resolved over
+    /// WebFinger and fetched by our server. Returns `Error::AccountNotFound`
+    /// if the handle doesn't belong to any account.
+    fn lookup_account(&self, acct: &str) -> Result<Account> {
+        parse_handle(acct)?;
+        let acct = acct.trim_start_matches('@');
+        let mut url: url::Url = self.route("/api/v1/accounts/lookup").parse()?;
+        url.query_pairs_mut().append_pair("acct", acct);
+        let response = self.send_blocking(self.client.get(url))?;
+
+        let status = response.status();
+
+        // Servers answer 404 both for accounts they haven't seen yet and when
+        // they predate the lookup endpoint.
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return self.resolve_account(acct);
+        } else if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Save the read positions for the home and/or notifications timelines
+    ///
+    /// Returns `Error::Conflict` if another client updated the markers at the
+    /// same time.
+    fn set_markers(&self, home: Option<&str>, notifications: Option<&str>) -> Result<Markers> {
+        let mut form_data = serde_json::Map::new();
+        if let Some(last_read_id) = home {
+            form_data.insert(
+                "home".to_string(),
+                serde_json::json!({ "last_read_id": last_read_id }),
+            );
+        }
+        if let Some(last_read_id) = notifications {
+            form_data.insert(
+                "notifications".to_string(),
+                serde_json::json!({ "last_read_id": last_read_id }),
+            );
+        }
+        let url = self.route("/api/v1/markers");
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::CONFLICT {
+            return Err(Error::Conflict);
+        } else if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn add_announcement_reaction(&self, id: &str, name: &str) -> Result<Empty> {
+        let mut url: url::Url = self
+            .route(&format!("/api/v1/announcements/{}/reactions", id))
+            .parse()?;
+        url.path_segments_mut()
+            .map_err(|_| Error::Other("Bad URL!".to_string()))?
+            .push(name);
+        let response = self.send_blocking(self.client.put(url))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    fn remove_announcement_reaction(&self, id: &str, name: &str) -> Result<Empty> {
+        let mut url: url::Url = self
+            .route(&format!("/api/v1/announcements/{}/reactions", id))
+            .parse()?;
+        url.path_segments_mut()
+            .map_err(|_| Error::Other("Bad URL!".to_string()))?
+            .push(name);
+        let response = self.send_blocking(self.client.delete(url))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
     fn update_credentials(&self, builder: UpdateCredsRequest) -> Result<Account> {
         let changes = builder.build()?;
         let url = self.route("/api/v1/accounts/update_credentials");
@@ -292,7 +844,11 @@ impl MastodonClient for Mastodon {
     }
 
     /// Post a new status to the account.
-    fn new_status(&self, status: NewStatus) -> Result<Status> {
+    ///
+    /// If the status was built with a `scheduled_at` time, the server queues
+    /// it and a `NewStatusResponse::Scheduled` is returned instead of the
+    /// published status.
+    fn new_status(&self, status: NewStatus) -> Result<NewStatusResponse> {
         let response = self.send_blocking(
             self.client
                 .post(&self.route("/api/v1/statuses"))
@@ -302,17 +858,108 @@ impl MastodonClient for Mastodon {
         deserialise_blocking(response)
     }
 
-    /// Get timeline filtered by a hashtag(eg. `#coffee`) either locally or
-    /// federated.
-    fn get_hashtag_timeline(&self, hashtag: &str, local: bool) -> Result<Page<Status>> {
-        let base = "/api/v1/timelines/tag/";
-        let url = if local {
-            self.route(&format!("{}{}?local=1", base, hashtag))
-        } else {
-            self.route(&format!("{}{}", base, hashtag))
-        };
+    /// Reblog a status with the given visibility, e.g. to only share it with
+    /// followers.
+    fn reblog_with_visibility(&self, id: &str, visibility: Visibility) -> Result<Status> {
+        let url = self.route(&format!("/api/v1/statuses/{}/reblog", id));
+        let form_data = serde_json::json!({ "visibility": visibility });
+        let response = self.send_blocking(self.client.post(&url).json(&form_data))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Edit an existing status.
+    fn edit_status(&self, id: &str, status: EditStatus) -> Result<Status> {
+        let url = self.route(&format!("/api/v1/statuses/{}", id));
+        let response = self.send_blocking(self.client.put(&url).json(&status))?;
+
+        let status = response.status();
+
+        if status.is_client_error() {
+            return Err(Error::Client(status));
+        } else if status.is_server_error() {
+            return Err(Error::Server(status));
+        }
+
+        deserialise_blocking(response)
+    }
+
+    /// Get the home timeline of the authenticated user.
+    fn get_home_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        self.timeline("/api/v1/timelines/home", request.into())
+    }
+
+    /// Get the public timeline, restricted to statuses from this instance.
+    fn get_local_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        let request = request.into().unwrap_or_default().local();
+        self.timeline("/api/v1/timelines/public", Some(request))
+    }
+
+    /// Get the public timeline of all known statuses.
+    fn get_federated_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        self.timeline("/api/v1/timelines/public", request.into())
+    }
+
+    /// Get timeline filtered by a hashtag(eg. `#coffee`), optionally also
+    /// matching other tags with `TimelineRequest::any_tag` and friends.
+    ///
+    /// ```no_run
+    /// # extern crate elefren;
+    /// # use elefren::prelude::*;
+    /// # use elefren::requests::TimelineRequest;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let data = Data {
+    /// #   base: "".into(),
+    /// #   client_id: "".into(),
+    /// #   client_secret: "".into(),
+    /// #   redirect: "".into(),
+    /// #   token: "".into(),
+    /// #   scopes: None,
+    /// # };
+    /// let client = Mastodon::from(data);
+    /// let request = TimelineRequest::new().local().none_tag("tea");
+    /// let statuses = client.get_hashtag_timeline("coffee", request)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_hashtag_timeline<'a, 'b: 'a, S>(
+        &'b self,
+        hashtag: &'b str,
+        request: S,
+    ) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        self.timeline(
+            &format!("/api/v1/timelines/tag/{}", hashtag),
+            request.into(),
+        )
+    }
 
-        Page::new(self, self.send_blocking(self.client.get(&url))?)
+    /// Get the statuses of the members of a list.
+    fn get_list_timeline<'a, 'b: 'a, S>(&'b self, id: &'b str, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        self.timeline(&format!("/api/v1/timelines/list/{}", id), request.into())
     }
 
     /// Get statuses of a single account by id. Optionally only with pictures
@@ -331,6 +978,7 @@ impl MastodonClient for Mastodon {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// let client = Mastodon::from(data);
     /// let statuses = client.statuses("user-id", None)?;
@@ -349,6 +997,7 @@ impl MastodonClient for Mastodon {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// let client = Mastodon::from(data);
     /// let request = StatusesRequest::new()
@@ -372,6 +1021,44 @@ impl MastodonClient for Mastodon {
         Page::new(self, response)
     }
 
+    /// Get the notifications for the authenticated user, optionally filtered
+    /// by type or by the account that triggered them.
+    ///
+    /// ```no_run
+    /// # extern crate elefren;
+    /// # use elefren::prelude::*;
+    /// # use elefren::{entities::notification::NotificationType, requests::NotificationsRequest};
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let data = Data {
+    /// #   base: "".into(),
+    /// #   client_id: "".into(),
+    /// #   client_secret: "".into(),
+    /// #   redirect: "".into(),
+    /// #   token: "".into(),
+    /// #   scopes: None,
+    /// # };
+    /// let client = Mastodon::from(data);
+    /// let request = NotificationsRequest::new().types(&[NotificationType::Mention]);
+    /// let mentions = client.notifications(request)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn notifications<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Notification>>
+    where
+        S: Into<Option<NotificationsRequest<'a>>>,
+    {
+        let mut url = self.route("/api/v1/notifications");
+
+        if let Some(request) = request.into() {
+            url = format!("{}{}", url, request.to_querystring()?);
+        }
+
+        let response = self.send_blocking(self.client.get(&url))?;
+
+        Page::new(self, response)
+    }
+
     /// Returns the client account's relationship to a list of other accounts.
     /// Such as whether they follow them or vice versa.
     fn relationships(&self, ids: &[&str]) -> Result<Page<Relationship>> {
@@ -448,6 +1135,7 @@ impl MastodonClient for Mastodon {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// let client = Mastodon::from(data);
     /// for event in client.streaming_user()? {
@@ -456,6 +1144,7 @@ impl MastodonClient for Mastodon {
     ///         Event::Notification(ref notification) => { /* .. */ },
     ///         Event::Delete(ref id) => { /* .. */ },
     ///         Event::FiltersChanged => { /* .. */ },
+    ///         Event::Conversation(ref conversation) => { /* .. */ },
     ///     }
     /// }
     /// # Ok(())
@@ -755,6 +1444,13 @@ impl<R: EventStream> EventReader<R> {
                 Event::Delete(data)
             },
             "filters_changed" => Event::FiltersChanged,
+            "conversation" => {
+                let data = data.ok_or_else(|| {
+                    Error::Other("Missing `data` line for conversation".to_string())
+                })?;
+                let conversation = serde_json::from_str::<Conversation>(&data)?;
+                Event::Conversation(conversation)
+            },
             _ => return Err(Error::Other(format!("Unknown event `{}`", event))),
         })
     }
@@ -886,6 +1582,38 @@ impl MastodonUnauthenticated for MastodonUnauth {
     }
 }
 
+// The part of a WebFinger response that's needed to resolve an account.
+#[derive(serde::Deserialize)]
+struct WebfingerResource {
+    subject: String,
+}
+
+// Split a `user@domain` or `@user@domain` handle into the username and the
+// domain, which is `None` for local accounts.
+fn parse_handle(acct: &str) -> Result<(&str, Option<&str>)> {
+    let handle = acct.strip_prefix('@').unwrap_or(acct);
+    let (username, domain) = match handle.find('@') {
+        Some(at) => (&handle[..at], Some(&handle[at + 1..])),
+        None => (handle, None),
+    };
+    let malformed = username.is_empty()
+        || match domain {
+            Some(domain) => domain.is_empty() || domain.contains(&['@', '/', '?', '#'][..]),
+            None => false,
+        };
+    if malformed {
+        return Err(Error::AccountNotFound(acct.to_string()));
+    }
+    Ok((username, domain))
+}
+
+fn webfinger_url(username: &str, domain: &str) -> Result<url::Url> {
+    let mut url: url::Url = format!("https://{}/.well-known/webfinger", domain).parse()?;
+    url.query_pairs_mut()
+        .append_pair("resource", &format!("acct:{}@{}", username, domain));
+    Ok(url)
+}
+
 // Convert the HTTP response body from JSON. Pass up deserialization errors
 // transparently.
 fn deserialise_blocking<T: for<'de> serde::Deserialize<'de>>(response: Response) -> Result<T> {
@@ -909,3 +1637,276 @@ fn deserialise_blocking<T: for<'de> serde::Deserialize<'de>>(response: Response)
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufReader, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+        thread,
+    };
+
+    const ACCOUNT: &str = r#"{
+        "id": "1",
+        "username": "Someone",
+        "acct": "Someone",
+        "display_name": "",
+        "locked": false,
+        "bot": false,
+        "created_at": "2022-09-08T00:00:00.000Z",
+        "note": "",
+        "url": "http://127.0.0.1/@Someone",
+        "avatar": "http://127.0.0.1/avatars/original/missing.png",
+        "avatar_static": "http://127.0.0.1/avatars/original/missing.png",
+        "header": "http://127.0.0.1/headers/original/missing.png",
+        "header_static": "http://127.0.0.1/headers/original/missing.png",
+        "followers_count": 0,
+        "following_count": 0,
+        "statuses_count": 0,
+        "emojis": [],
+        "fields": []
+    }"#;
+
+    // Reads the request line and headers of a request to a fake server
+    fn read_request(stream: &TcpStream) -> (String, Vec<String>) {
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).expect("Couldn't read");
+        let mut headers = Vec::new();
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).expect("Couldn't read");
+            if header.trim().is_empty() {
+                break;
+            }
+            headers.push(header.trim().to_string());
+        }
+        (request_line, headers)
+    }
+
+    // A server answering every request with whatever `respond` returns for
+    // its request line and headers.
+    fn serve<F>(respond: F) -> String
+    where
+        F: Fn(&str, &[String]) -> (&'static str, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind");
+        let base = format!("http://{}", listener.local_addr().expect("No address"));
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.expect("Couldn't accept");
+                let (request_line, headers) = read_request(&stream);
+                let (status, body) = respond(&request_line, &headers);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: \
+                     {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .expect("Couldn't respond");
+            }
+        });
+        base
+    }
+
+    // A server that doesn't know any account by lookup, and returns the given
+    // accounts from every search.
+    fn fake_server(accounts: &'static str) -> String {
+        serve(move |request_line, _| {
+            if request_line.contains("/api/v1/accounts/lookup") {
+                (
+                    "404 Not Found",
+                    r#"{"error":"Record not found"}"#.to_string(),
+                )
+            } else if request_line.contains("/api/v2/search") {
+                (
+                    "200 OK",
+                    format!(
+                        r#"{{"accounts":[{}],"statuses":[],"hashtags":[]}}"#,
+                        accounts
+                    ),
+                )
+            } else {
+                ("500 Internal Server Error", String::new())
+            }
+        })
+    }
+
+    fn mastodon(base: String) -> Mastodon {
+        Mastodon::from(Data {
+            base: base.into(),
+            client_id: "".into(),
+            client_secret: "".into(),
+            redirect: "".into(),
+            token: "the-token".into(),
+            scopes: None,
+        })
+    }
+
+    fn block_on<T>(f: impl FnOnce() -> T) -> T {
+        let runtime = tokio::runtime::Builder::new()
+            .threaded_scheduler()
+            .enable_all()
+            .build()
+            .expect("Couldn't build runtime");
+        let handle = runtime.handle().clone();
+        let result = handle.enter(f);
+        runtime.shutdown_timeout(std::time::Duration::from_millis(100));
+        result
+    }
+
+    fn lookup(base: String, acct: &str) -> Result<Account> {
+        let mastodon = mastodon(base);
+        block_on(|| mastodon.lookup_account(acct))
+    }
+
+    #[test]
+    fn test_set_markers_conflict() {
+        let base = serve(|request_line, _| {
+            if request_line.starts_with("POST /api/v1/markers") {
+                ("409 Conflict", r#"{"error":"Conflict"}"#.to_string())
+            } else {
+                ("500 Internal Server Error", String::new())
+            }
+        });
+        let mastodon = mastodon(base);
+        match block_on(|| mastodon.set_markers(Some("1"), None)) {
+            Err(Error::Conflict) => {},
+            other => panic!("expected Conflict, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get_markers() {
+        let base = serve(|request_line, _| {
+            if request_line.starts_with("GET /api/v1/markers?timeline%5B%5D=home ") {
+                (
+                    "200 OK",
+                    r#"{"home":{"last_read_id":"1","version":2,"updated_at":"2019-11-26T22:37:25.239Z"}}"#
+                        .to_string(),
+                )
+            } else {
+                ("500 Internal Server Error", String::new())
+            }
+        });
+        let mastodon = mastodon(base);
+        let markers = block_on(|| mastodon.get_markers(&[MarkerTimeline::Home]))
+            .expect("Couldn't get markers");
+        assert_eq!(markers.home.map(|marker| marker.version), Some(2));
+        assert_eq!(markers.notifications, None);
+    }
+
+    #[test]
+    fn test_conversation_event() {
+        let conversation = format!(
+            r#"{{"id":"418450","unread":true,"accounts":[{}],"last_status":null}}"#,
+            ACCOUNT.replace('\n', "")
+        );
+        let stream = format!(":thump\nevent: conversation\ndata: {}\n\n", conversation);
+        let mut events = EventReader(std::io::Cursor::new(stream));
+        match events.next() {
+            Some(Event::Conversation(conversation)) => {
+                assert_eq!(conversation.id, "418450");
+                assert!(conversation.unread);
+                assert_eq!(conversation.accounts[0].id, "1");
+            },
+            other => panic!("expected a conversation event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_handle() {
+        assert_eq!(
+            parse_handle("@user@example.com").expect("Couldn't parse handle"),
+            ("user", Some("example.com"))
+        );
+        assert_eq!(
+            parse_handle("user@example.com").expect("Couldn't parse handle"),
+            ("user", Some("example.com"))
+        );
+        assert_eq!(
+            parse_handle("@user").expect("Couldn't parse handle"),
+            ("user", None)
+        );
+        for malformed in &["", "@", "@@example.com", "user@", "user@a@b", "user@a/b"] {
+            assert!(
+                matches!(parse_handle(malformed), Err(Error::AccountNotFound(_))),
+                "accepted {:?}",
+                malformed
+            );
+        }
+    }
+
+    #[test]
+    fn test_webfinger_url() {
+        assert_eq!(
+            webfinger_url("user", "example.com")
+                .expect("Couldn't build url")
+                .as_str(),
+            "https://example.com/.well-known/webfinger?resource=acct%3Auser%40example.com"
+        );
+    }
+
+    #[test]
+    fn test_lookup_account_falls_back_to_search() {
+        let base = fake_server(ACCOUNT);
+        let account = lookup(base, "@someone").expect("Couldn't look up account");
+        assert_eq!(account.id, "1");
+    }
+
+    #[test]
+    fn test_lookup_account_not_found() {
+        let base = fake_server("");
+        match lookup(base, "nobody") {
+            Err(Error::AccountNotFound(acct)) => assert_eq!(acct, "nobody"),
+            other => panic!("expected AccountNotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_webfinger_is_unauthenticated() {
+        let (tx, rx) = mpsc::channel();
+        let remote = serve(move |_, headers| {
+            tx.send(headers.to_vec()).expect("Couldn't send headers");
+            (
+                "200 OK",
+                r#"{"subject":"acct:someone@remote.example"}"#.to_string(),
+            )
+        });
+        let url = format!(
+            "{}/.well-known/webfinger?resource=acct%3Asomeone%40remote.example",
+            remote
+        );
+        let mastodon = mastodon("http://127.0.0.1:1".to_string());
+        let handle = block_on(|| {
+            mastodon.webfinger(
+                url.parse().expect("Couldn't parse url"),
+                "someone@remote.example",
+            )
+        })
+        .expect("Couldn't finger account");
+        assert_eq!(handle, "someone@remote.example");
+
+        let headers = rx.recv().expect("No request reached the remote server");
+        assert!(
+            !headers
+                .iter()
+                .any(|header| header.to_ascii_lowercase().starts_with("authorization:")),
+            "sent {:?}",
+            headers
+        );
+    }
+
+    #[test]
+    fn test_lookup_account_malformed() {
+        // Rejected before any request is made
+        match lookup("http://127.0.0.1:1".to_string(), "user@") {
+            Err(Error::AccountNotFound(acct)) => assert_eq!(acct, "user@"),
+            other => panic!("expected AccountNotFound, got {:?}", other),
+        }
+    }
+}
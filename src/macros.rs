@@ -33,6 +33,7 @@ macro_rules! paged_routes {
             "#     client_secret: \"htnjdiuae\".into(),\n",
             "#     redirect: \"https://example.com\".into(),\n",
             "#     token: \"tsaohueaheis\".into(),\n",
+            "#     scopes: None,\n",
             "# };\n",
             "let client = Mastodon::from(data);\n",
             "client.", stringify!($name), "();\n",
@@ -236,6 +237,7 @@ macro_rules! route {
                 "#     client_secret: \"htnjdiuae\".into(),\n",
                 "#     redirect: \"https://example.com\".into(),\n",
                 "#     token: \"tsaohueaheis\".into(),\n",
+                "#     scopes: None,\n",
                 "# };\n",
                 "let client = Mastodon::from(data);\n",
                 "client.", stringify!($name), "();\n",
@@ -274,6 +276,7 @@ macro_rules! route_id {
                     "#     client_secret: \"htnjdiuae\".into(),\n",
                     "#     redirect: \"https://example.com\".into(),\n",
                     "#     token: \"tsaohueaheis\".into(),\n",
+                    "#     scopes: None,\n",
                     "# };\n",
                     "let client = Mastodon::from(data);\n",
                     "client.", stringify!($name), "(\"42\");\n",
@@ -288,6 +291,42 @@ macro_rules! route_id {
          )*
     }
 
+}
+macro_rules! route_v2_id {
+
+    ($(($method:ident) $name:ident: $url:expr => $ret:ty,)*) => {
+        $(
+            doc_comment::doc_comment! {
+                concat!(
+                    "Equivalent to `", stringify!($method), " /api/v2/",
+                    $url,
+                    "`\n# Errors\nIf `access_token` is not set.",
+                    "\n",
+                    "```no_run",
+                    "# extern crate elefren;\n",
+                    "# use elefren::prelude::*;\n",
+                    "# fn main() -> Result<(), Box<::std::error::Error>> {\n",
+                    "# let data = Data {\n",
+                    "#     base: \"https://example.com\".into(),\n",
+                    "#     client_id: \"taosuah\".into(),\n",
+                    "#     client_secret: \"htnjdiuae\".into(),\n",
+                    "#     redirect: \"https://example.com\".into(),\n",
+                    "#     token: \"tsaohueaheis\".into(),\n",
+                    "#     scopes: None,\n",
+                    "# };\n",
+                    "let client = Mastodon::from(data);\n",
+                    "client.", stringify!($name), "(\"42\");\n",
+                    "#   Ok(())\n",
+                    "# }\n",
+                    "```"
+                ),
+                fn $name(&self, id: &str) -> Result<$ret> {
+                    self.$method(self.route(&format!(concat!("/api/v2/", $url), id)))
+                }
+            }
+         )*
+    }
+
 }
 macro_rules! paged_routes_with_id {
 
@@ -308,6 +347,7 @@ macro_rules! paged_routes_with_id {
                 "#     client_secret: \"htnjdiuae\".into(),\n",
                 "#     redirect: \"https://example.com\".into(),\n",
                 "#     token: \"tsaohueaheis\".into(),\n",
+                "#     scopes: None,\n",
                 "# };\n",
                 "let client = Mastodon::from(data);\n",
                 "client.", stringify!($name), "(\"some-id\");\n",
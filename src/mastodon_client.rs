@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use chrono::prelude::*;
+
 use crate::{
     entities::prelude::*,
     errors::Result,
@@ -8,11 +10,20 @@ use crate::{
     requests::{
         AddFilterRequest,
         AddPushRequest,
+        AdminAccountsRequest,
+        AdminActionRequest,
+        FilterRequest,
+        FollowOptions,
+        ListRequest,
+        MuteOptions,
+        NotificationsRequest,
+        ReportRequest,
         StatusesRequest,
+        TimelineRequest,
         UpdateCredsRequest,
         UpdatePushRequest,
     },
-    status_builder::NewStatus,
+    status_builder::{EditStatus, NewStatus, Visibility},
 };
 
 /// Represents the set of methods that a Mastodon Client can do, so that
@@ -27,6 +38,10 @@ pub trait MastodonClient {
     fn favourites(&self) -> Result<Page<Status>> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v1/bookmarks
+    fn bookmarks(&self) -> Result<Page<Status>> {
+        unimplemented!("This method was not implemented");
+    }
     /// GET /api/v1/blocks
     fn blocks(&self) -> Result<Page<Account>> {
         unimplemented!("This method was not implemented");
@@ -40,7 +55,10 @@ pub trait MastodonClient {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/timelines/home
-    fn get_home_timeline(&self) -> Result<Page<Status>> {
+    fn get_home_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/custom_emojis
@@ -52,7 +70,10 @@ pub trait MastodonClient {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/notifications
-    fn notifications(&self) -> Result<Page<Notification>> {
+    fn notifications<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Notification>>
+    where
+        S: Into<Option<NotificationsRequest<'a>>>,
+    {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/reports
@@ -83,12 +104,43 @@ pub trait MastodonClient {
     fn instance(&self) -> Result<Instance> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v2/instance
+    fn instance_v2(&self) -> Result<InstanceV2> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/instance/rules
+    fn instance_rules(&self) -> Result<Vec<Rule>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/instance/peers
+    fn peers(&self) -> Result<Vec<String>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/instance/activity
+    fn activity(&self) -> Result<Vec<Activity>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/instance/extended_description
+    fn extended_description(&self) -> Result<ExtendedDescription> {
+        unimplemented!("This method was not implemented");
+    }
     /// GET /api/v1/accounts/verify_credentials
     fn verify_credentials(&self) -> Result<Account> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v1/apps/verify_credentials
+    fn verify_app_credentials(&self) -> Result<Application> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /oauth/revoke
+    ///
+    /// Revokes the access token this client was created with, e.g. when a
+    /// user disconnects their account. The client can't be used afterwards.
+    fn revoke_token(&self) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
     /// POST /api/v1/reports
-    fn report(&self, account_id: &str, status_ids: Vec<&str>, comment: String) -> Result<Report> {
+    fn report(&self, request: &ReportRequest) -> Result<Report> {
         unimplemented!("This method was not implemented");
     }
     /// POST /api/v1/domain_blocks
@@ -103,6 +155,10 @@ pub trait MastodonClient {
     fn reject_follow_request(&self, id: &str) -> Result<Empty> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v1/accounts/lookup
+    fn lookup_account(&self, acct: &str) -> Result<Account> {
+        unimplemented!("This method was not implemented");
+    }
     /// GET /api/v1/search
     fn search(&self, q: &str, resolve: bool) -> Result<SearchResult> {
         unimplemented!("This method was not implemented");
@@ -132,7 +188,10 @@ pub trait MastodonClient {
         unimplemented!("This method was not implemented");
     }
     /// POST /api/v1/accounts/:id/follow
-    fn follow(&self, id: &str) -> Result<Relationship> {
+    fn follow<O>(&self, id: &str, options: O) -> Result<Relationship>
+    where
+        O: Into<Option<FollowOptions>>,
+    {
         unimplemented!("This method was not implemented");
     }
     /// POST /api/v1/accounts/:id/unfollow
@@ -147,11 +206,14 @@ pub trait MastodonClient {
     fn unblock(&self, id: &str) -> Result<Relationship> {
         unimplemented!("This method was not implemented");
     }
-    /// GET /api/v1/accounts/:id/mute
-    fn mute(&self, id: &str) -> Result<Relationship> {
+    /// POST /api/v1/accounts/:id/mute
+    fn mute<O>(&self, id: &str, options: O) -> Result<Relationship>
+    where
+        O: Into<Option<MuteOptions>>,
+    {
         unimplemented!("This method was not implemented");
     }
-    /// GET /api/v1/accounts/:id/unmute
+    /// POST /api/v1/accounts/:id/unmute
     fn unmute(&self, id: &str) -> Result<Relationship> {
         unimplemented!("This method was not implemented");
     }
@@ -175,10 +237,30 @@ pub trait MastodonClient {
     fn reblog(&self, id: &str) -> Result<Status> {
         unimplemented!("This method was not implemented");
     }
+    /// POST /api/v1/statuses/:id/reblog
+    fn reblog_with_visibility(&self, id: &str, visibility: Visibility) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
     /// POST /api/v1/statuses/:id/unreblog
     fn unreblog(&self, id: &str) -> Result<Status> {
         unimplemented!("This method was not implemented");
     }
+    /// POST /api/v1/statuses/:id/pin
+    fn pin_status(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/statuses/:id/unpin
+    fn unpin_status(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/statuses/:id/mute
+    fn mute_status(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/statuses/:id/unmute
+    fn unmute_status(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
     /// POST /api/v1/statuses/:id/favourite
     fn favourite(&self, id: &str) -> Result<Status> {
         unimplemented!("This method was not implemented");
@@ -187,6 +269,14 @@ pub trait MastodonClient {
     fn unfavourite(&self, id: &str) -> Result<Status> {
         unimplemented!("This method was not implemented");
     }
+    /// POST /api/v1/statuses/:id/bookmark
+    fn bookmark(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/statuses/:id/unbookmark
+    fn unbookmark(&self, id: &str) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
     /// DELETE /api/v1/statuses/:id
     fn delete_status(&self, id: &str) -> Result<Empty> {
         unimplemented!("This method was not implemented");
@@ -196,19 +286,51 @@ pub trait MastodonClient {
         unimplemented!("This method was not implemented");
     }
     /// POST /api/v1/statuses
-    fn new_status(&self, status: NewStatus) -> Result<Status> {
+    fn new_status(&self, status: NewStatus) -> Result<NewStatusResponse> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v1/statuses/:id
+    fn edit_status(&self, id: &str, status: EditStatus) -> Result<Status> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/statuses/:id/history
+    fn status_history(&self, id: &str) -> Result<Vec<StatusEdit>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/statuses/:id/source
+    fn status_source(&self, id: &str) -> Result<StatusSource> {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/timelines/public?local=true
-    fn get_local_timeline(&self) -> Result<Page<Status>> {
+    fn get_local_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
         unimplemented!("This method was not implemented");
     }
-    /// GET /api/v1/timelines/public?local=false
-    fn get_federated_timeline(&self) -> Result<Page<Status>> {
+    /// GET /api/v1/timelines/public
+    fn get_federated_timeline<'a, 'b: 'a, S>(&'b self, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/timelines/tag/:hashtag
-    fn get_hashtag_timeline(&self, hashtag: &str, local: bool) -> Result<Page<Status>> {
+    fn get_hashtag_timeline<'a, 'b: 'a, S>(
+        &'b self,
+        hashtag: &'b str,
+        request: S,
+    ) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/timelines/list/:list_id
+    fn get_list_timeline<'a, 'b: 'a, S>(&'b self, id: &'b str, request: S) -> Result<Page<Status>>
+    where
+        S: Into<Option<TimelineRequest<'a>>>,
+    {
         unimplemented!("This method was not implemented");
     }
     /// GET /api/v1/accounts/:id/statuses
@@ -267,6 +389,72 @@ pub trait MastodonClient {
     fn delete_filter(&self, id: &str) -> Result<Empty> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v2/filters
+    fn get_filters_v2(&self) -> Result<Vec<FilterV2>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v2/filters
+    fn add_filter_v2(&self, request: &FilterRequest) -> Result<FilterV2> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/filters/:id
+    fn get_filter_v2(&self, id: &str) -> Result<FilterV2> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v2/filters/:id
+    fn update_filter_v2(&self, id: &str, request: &FilterRequest) -> Result<FilterV2> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v2/filters/:id
+    fn delete_filter_v2(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/filters/:filter_id/keywords
+    fn get_filter_keywords(&self, filter_id: &str) -> Result<Vec<FilterKeyword>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v2/filters/:filter_id/keywords
+    fn add_filter_keyword(
+        &self,
+        filter_id: &str,
+        keyword: &str,
+        whole_word: bool,
+    ) -> Result<FilterKeyword> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/filters/keywords/:id
+    fn get_filter_keyword(&self, id: &str) -> Result<FilterKeyword> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v2/filters/keywords/:id
+    fn update_filter_keyword(
+        &self,
+        id: &str,
+        keyword: &str,
+        whole_word: bool,
+    ) -> Result<FilterKeyword> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v2/filters/keywords/:id
+    fn delete_filter_keyword(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/filters/:filter_id/statuses
+    fn get_filter_statuses(&self, filter_id: &str) -> Result<Vec<FilterStatus>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v2/filters/:filter_id/statuses
+    fn add_filter_status(&self, filter_id: &str, status_id: &str) -> Result<FilterStatus> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/filters/statuses/:id
+    fn get_filter_status(&self, id: &str) -> Result<FilterStatus> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v2/filters/statuses/:id
+    fn delete_filter_status(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
     /// GET /api/v1/suggestions
     fn get_follow_suggestions(&self) -> Result<Vec<Account>> {
         unimplemented!("This method was not implemented");
@@ -287,6 +475,182 @@ pub trait MastodonClient {
     fn unendorse_user(&self, id: &str) -> Result<Relationship> {
         unimplemented!("This method was not implemented");
     }
+    /// GET /api/v1/lists
+    fn get_lists(&self) -> Result<Vec<List>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/lists/:id
+    fn get_list(&self, id: &str) -> Result<List> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/lists
+    fn create_list(&self, request: &ListRequest) -> Result<List> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v1/lists/:id
+    fn update_list(&self, id: &str, request: &ListRequest) -> Result<List> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/lists/:id
+    fn delete_list(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/lists/:id/accounts
+    fn list_accounts(&self, id: &str) -> Result<Page<Account>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/lists/:id/accounts
+    fn add_to_list(&self, id: &str, account_ids: &[&str]) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/lists/:id/accounts
+    fn remove_from_list(&self, id: &str, account_ids: &[&str]) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/accounts/:id/lists
+    fn get_account_lists(&self, id: &str) -> Result<Vec<List>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/polls/:id
+    fn get_poll(&self, id: &str) -> Result<Poll> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/polls/:id/votes
+    fn vote(&self, id: &str, choices: &[u64]) -> Result<Poll> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/scheduled_statuses
+    fn scheduled_statuses(&self) -> Result<Page<ScheduledStatus>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/scheduled_statuses/:id
+    fn get_scheduled_status(&self, id: &str) -> Result<ScheduledStatus> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v1/scheduled_statuses/:id
+    fn reschedule_status(&self, id: &str, scheduled_at: DateTime<Utc>) -> Result<ScheduledStatus> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/scheduled_statuses/:id
+    fn cancel_scheduled_status(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/conversations
+    fn conversations(&self) -> Result<Page<Conversation>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/conversations/:id/read
+    fn mark_conversation_read(&self, id: &str) -> Result<Conversation> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/conversations/:id
+    fn delete_conversation(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/markers
+    fn get_markers(&self, timelines: &[MarkerTimeline]) -> Result<Markers> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/markers
+    fn set_markers(&self, home: Option<&str>, notifications: Option<&str>) -> Result<Markers> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/announcements
+    fn get_announcements(&self, with_dismissed: bool) -> Result<Vec<Announcement>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/announcements/:id/dismiss
+    fn dismiss_announcement(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// PUT /api/v1/announcements/:id/reactions/:name
+    fn add_announcement_reaction(&self, id: &str, name: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/announcements/:id/reactions/:name
+    fn remove_announcement_reaction(&self, id: &str, name: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/featured_tags
+    fn get_featured_tags(&self) -> Result<Vec<FeaturedTag>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/featured_tags
+    fn feature_tag(&self, name: &str) -> Result<FeaturedTag> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/featured_tags/:id
+    fn unfeature_tag(&self, id: &str) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/featured_tags/suggestions
+    fn get_featured_tag_suggestions(&self) -> Result<Vec<Tag>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/accounts/:id/featured_tags
+    fn get_account_featured_tags(&self, id: &str) -> Result<Vec<FeaturedTag>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/tags/:id
+    fn get_tag(&self, id: &str) -> Result<Tag> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/tags/:id/follow
+    fn follow_tag(&self, id: &str) -> Result<Tag> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/tags/:id/unfollow
+    fn unfollow_tag(&self, id: &str) -> Result<Tag> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/followed_tags
+    fn followed_tags(&self) -> Result<Page<Tag>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/admin/accounts
+    fn admin_accounts(&self) -> Result<Page<AdminAccount>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v2/admin/accounts
+    fn admin_accounts_v2(&self, request: &AdminAccountsRequest) -> Result<Page<AdminAccount>> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/admin/accounts/:id
+    fn admin_get_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/approve
+    fn admin_approve_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/reject
+    fn admin_reject_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/enable
+    fn admin_enable_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/unsilence
+    fn admin_unsilence_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/unsuspend
+    fn admin_unsuspend_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// DELETE /api/v1/admin/accounts/:id
+    fn admin_delete_account(&self, id: &str) -> Result<AdminAccount> {
+        unimplemented!("This method was not implemented");
+    }
+    /// POST /api/v1/admin/accounts/:id/action
+    fn admin_account_action(&self, id: &str, request: &AdminActionRequest) -> Result<Empty> {
+        unimplemented!("This method was not implemented");
+    }
+    /// GET /api/v1/preferences
+    fn get_preferences(&self) -> Result<Preferences> {
+        unimplemented!("This method was not implemented");
+    }
     /// Shortcut for: `let me = client.verify_credentials(); client.followers()`
     ///
     /// ```no_run
@@ -300,6 +664,7 @@ pub trait MastodonClient {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// # let client = Mastodon::from(data);
     /// let follows_me = client.follows_me()?;
@@ -322,6 +687,7 @@ pub trait MastodonClient {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// # let client = Mastodon::from(data);
     /// let follows_me = client.followed_by_me()?;
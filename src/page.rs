@@ -50,13 +50,14 @@ macro_rules! pages {
 /// #   client_secret: "".into(),
 /// #   redirect: "".into(),
 /// #   token: "".into(),
+/// #   scopes: None,
 /// # };
 /// struct HomeTimeline {
 ///     client: Mastodon,
 ///     page: RefCell<Option<OwnedPage<Status>>>,
 /// }
 /// let client = Mastodon::from(data);
-/// let home = client.get_home_timeline()?.into_owned();
+/// let home = client.get_home_timeline(None)?.into_owned();
 /// let tl = HomeTimeline {
 ///     client,
 ///     page: RefCell::new(Some(home)),
@@ -138,13 +139,14 @@ impl<'a, T: Clone + for<'de> Deserialize<'de>> Page<'a, T> {
     /// #   client_secret: "".into(),
     /// #   redirect: "".into(),
     /// #   token: "".into(),
+    /// #   scopes: None,
     /// # };
     /// struct HomeTimeline {
     ///     client: Mastodon,
     ///     page: RefCell<Option<OwnedPage<Status>>>,
     /// }
     /// let client = Mastodon::from(data);
-    /// let home = client.get_home_timeline()?.into_owned();
+    /// let home = client.get_home_timeline(None)?.into_owned();
     /// let tl = HomeTimeline {
     ///     client,
     ///     page: RefCell::new(Some(home)),
@@ -179,6 +181,7 @@ impl<'a, T: Clone + for<'de> Deserialize<'de>> Page<'a, T> {
     /// #       client_secret: "".into(),
     /// #       redirect: "".into(),
     /// #       token: "".into(),
+    /// #       scopes: None,
     /// #   };
     /// let mastodon = Mastodon::from(data);
     /// let req = StatusesRequest::new();
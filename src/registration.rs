@@ -1,11 +1,15 @@
 use std::borrow::Cow;
 
+use chrono::prelude::*;
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
 use reqwest::{Client, RequestBuilder, Response};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use std::convert::TryInto;
 
 use crate::{
     apps::{App, AppBuilder},
+    errors::OAuthError,
     scopes::Scopes,
     Data,
     Error,
@@ -14,7 +18,7 @@ use crate::{
     Result,
 };
 
-const DEFAULT_REDIRECT_URI: &str = "urn:ietf:wg:oauth:2.0:oob";
+pub(crate) const DEFAULT_REDIRECT_URI: &str = "urn:ietf:wg:oauth:2.0:oob";
 
 /// Handles registering your mastodon app to your instance. It is recommended
 /// you cache your data struct to avoid registering on every run.
@@ -24,23 +28,106 @@ pub struct Registration<'a> {
     client: Client,
     app_builder: AppBuilder<'a>,
     force_login: bool,
+    pkce: bool,
 }
 
 #[derive(Deserialize)]
-struct OAuth {
-    client_id: String,
-    client_secret: String,
+pub(crate) struct OAuth {
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
     #[serde(default = "default_redirect_uri")]
-    redirect_uri: String,
+    pub(crate) redirect_uri: String,
 }
 
 fn default_redirect_uri() -> String {
     DEFAULT_REDIRECT_URI.to_string()
 }
 
-#[derive(Deserialize)]
-struct AccessToken {
-    access_token: String,
+/// The response to a successful token request
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AccessToken {
+    /// The access token, to be sent as a bearer token.
+    pub access_token: String,
+    /// The type of the token, `Bearer` for Mastodon.
+    pub token_type: String,
+    /// The space-separated scopes that were granted, if the server said.
+    pub scope: Option<String>,
+    /// When the token was created.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl AccessToken {
+    /// The scopes that were granted to the token.
+    ///
+    /// Servers only leave the scope out of the response if the requested
+    /// scopes were granted, so those are returned in that case. Returns `None`
+    /// if the granted scopes couldn't be parsed.
+    pub fn granted_scopes(&self, requested: &Scopes) -> Option<Scopes> {
+        match self.scope {
+            Some(ref scope) => match scope.parse() {
+                Ok(scopes) => Some(scopes),
+                Err(e) => {
+                    log::warn!("couldn't parse granted scopes {:?}: {}", scope, e);
+                    None
+                },
+            },
+            None => Some(requested.clone()),
+        }
+    }
+}
+
+/// Parses the response to a request to `/oauth/token`, turning error
+/// responses into `Error::OAuth` when the server describes the error
+pub(crate) fn parse_token_response(
+    status: reqwest::StatusCode,
+    body: &[u8],
+) -> Result<AccessToken> {
+    if status.is_success() {
+        return Ok(serde_json::from_slice(body)?);
+    }
+    if let Ok(error) = serde_json::from_slice::<OAuthError>(body) {
+        return Err(Error::OAuth(error));
+    }
+    if status.is_server_error() {
+        Err(Error::Server(status))
+    } else {
+        Err(Error::Client(status))
+    }
+}
+
+/// Generates a random `state` parameter for the authorization page
+pub(crate) fn generate_state() -> String {
+    thread_rng().sample_iter(&Alphanumeric).take(32).collect()
+}
+
+/// Generates a random PKCE code verifier
+pub(crate) fn generate_code_verifier() -> String {
+    thread_rng().sample_iter(&Alphanumeric).take(64).collect()
+}
+
+/// The S256 code challenge for the given PKCE code verifier
+pub(crate) fn code_challenge(code_verifier: &str) -> String {
+    base64::encode_config(
+        Sha256::digest(code_verifier.as_bytes()),
+        base64::URL_SAFE_NO_PAD,
+    )
+}
+
+/// Compares the expected and returned `state` parameters without returning
+/// early on the first differing byte
+pub(crate) fn verify_state(expected: &str, state: &str) -> Result<()> {
+    let matches = expected.len() == state.len()
+        && expected
+            .bytes()
+            .zip(state.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+    if matches {
+        Ok(())
+    } else {
+        Err(Error::StateMismatch)
+    }
 }
 
 impl<'a> Registration<'a> {
@@ -56,6 +143,7 @@ impl<'a> Registration<'a> {
             client: Client::new(),
             app_builder: AppBuilder::new(),
             force_login: false,
+            pkce: true,
         }
     }
 }
@@ -97,6 +185,15 @@ impl<'a> Registration<'a> {
         self
     }
 
+    /// Sets whether to use PKCE, sending a `code_challenge` to the
+    /// authorization page and the matching `code_verifier` when completing
+    /// the flow. This is on by default, and recommended for apps that can't
+    /// keep their client secret private.
+    pub fn pkce(&mut self, pkce: bool) -> &mut Self {
+        self.pkce = pkce;
+        self
+    }
+
     fn send(&self, req: RequestBuilder) -> Result<Response> {
         let req = req.build()?;
         let handle = tokio::runtime::Handle::current();
@@ -120,7 +217,7 @@ impl<'a> Registration<'a> {
     /// let code = String::from("RETURNED_FROM_BROWSER");
     /// let mastodon = registration.complete(&code)?;
     ///
-    /// println!("{:?}", mastodon.get_home_timeline()?.initial_items);
+    /// println!("{:?}", mastodon.get_home_timeline(None)?.initial_items);
     /// # Ok(())
     /// # }
     /// ```
@@ -139,6 +236,12 @@ impl<'a> Registration<'a> {
             redirect: oauth.redirect_uri,
             scopes: app.scopes().clone(),
             force_login: self.force_login,
+            state: generate_state(),
+            code_verifier: if self.pkce {
+                Some(generate_code_verifier())
+            } else {
+                None
+            },
         })
     }
 
@@ -158,7 +261,7 @@ impl<'a> Registration<'a> {
     /// let code = String::from("RETURNED_FROM_BROWSER");
     /// let mastodon = registration.complete(&code)?;
     ///
-    /// println!("{:?}", mastodon.get_home_timeline()?.initial_items);
+    /// println!("{:?}", mastodon.get_home_timeline(None)?.initial_items);
     /// # Ok(())
     /// # }
     /// ```
@@ -174,6 +277,12 @@ impl<'a> Registration<'a> {
             redirect: oauth.redirect_uri,
             scopes: app.scopes().clone(),
             force_login: self.force_login,
+            state: generate_state(),
+            code_verifier: if self.pkce {
+                Some(generate_code_verifier())
+            } else {
+                None
+            },
         })
     }
 
@@ -188,6 +297,11 @@ impl Registered {
     /// Skip having to retrieve the client id and secret from the server by
     /// creating a `Registered` struct directly
     ///
+    /// The parts don't include the `state` and PKCE code verifier, so PKCE is
+    /// off for the result and a new `state` is generated. When restoring a
+    /// flow whose authorization url was already handed out, pass the saved
+    /// `state` and code verifier to `with_state` and `with_code_verifier`.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -209,7 +323,7 @@ impl Registered {
     /// let code = String::from("RETURNED_FROM_BROWSER");
     /// let mastodon = registration.complete(&code)?;
     ///
-    /// println!("{:?}", mastodon.get_home_timeline()?.initial_items);
+    /// println!("{:?}", mastodon.get_home_timeline(None)?.initial_items);
     /// #   Ok(())
     /// # }
     /// ```
@@ -229,6 +343,8 @@ impl Registered {
             redirect: redirect.to_string(),
             scopes,
             force_login,
+            state: generate_state(),
+            code_verifier: None,
         }
     }
 }
@@ -243,6 +359,9 @@ impl Registered {
     /// Returns the parts of the `Registered` struct that can be used to
     /// recreate another `Registered` struct
     ///
+    /// The `state` and PKCE code verifier are not included, save them from
+    /// `state` and `code_verifier` first if the flow is still in progress.
+    ///
     /// # Example
     ///
     /// ```
@@ -288,6 +407,59 @@ impl Registered {
         )
     }
 
+    /// The `state` parameter sent to the authorization page.
+    ///
+    /// If the flow is completed by another process, save this along with the
+    /// parts of this struct and restore it with `with_state`.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// Use the given `state` parameter instead of the generated one
+    pub fn with_state<S: Into<String>>(mut self, state: S) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    /// The PKCE code verifier sent when completing the flow, if PKCE is used
+    pub fn code_verifier(&self) -> Option<&str> {
+        self.code_verifier.as_deref()
+    }
+
+    /// Use the given PKCE code verifier instead of the generated one, or turn
+    /// PKCE off with `None`
+    pub fn with_code_verifier(mut self, code_verifier: Option<String>) -> Self {
+        self.code_verifier = code_verifier;
+        self
+    }
+
+    /// Checks the `state` parameter that the authorization page redirected
+    /// back with against the one that was sent, to guard against forged
+    /// redirects
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate elefren;
+    /// use elefren::{prelude::*, registration::Registered};
+    ///
+    /// let registered = Registered::from_parts(
+    ///     "https://example.com",
+    ///     "the-client-id",
+    ///     "the-client-secret",
+    ///     "https://example.com/redirect",
+    ///     Scopes::read_all(),
+    ///     false,
+    /// )
+    /// .with_state("some-state");
+    ///
+    /// assert!(registered.verify_state("some-state").is_ok());
+    /// assert!(registered.verify_state("forged").is_err());
+    /// ```
+    pub fn verify_state(&self, state: &str) -> Result<()> {
+        verify_state(&self.state, state)
+    }
+
     /// Returns the full url needed for authorisation. This needs to be opened
     /// in a browser.
     pub fn authorize_url(&self) -> Result<String> {
@@ -298,7 +470,13 @@ impl Registered {
             .append_pair("redirect_uri", &self.redirect)
             .append_pair("scope", &self.scopes.to_string())
             .append_pair("response_type", "code")
-            .append_pair("force_login", &self.force_login.to_string());
+            .append_pair("force_login", &self.force_login.to_string())
+            .append_pair("state", &self.state);
+        if let Some(ref code_verifier) = self.code_verifier {
+            url.query_pairs_mut()
+                .append_pair("code_challenge", &code_challenge(code_verifier))
+                .append_pair("code_challenge_method", "S256");
+        }
 
         Ok(url.into())
     }
@@ -306,21 +484,81 @@ impl Registered {
     /// Create an access token from the client id, client secret, and code
     /// provided by the authorisation url.
     pub fn complete(&self, code: &str) -> Result<Mastodon> {
-        let url = format!(
-            "{}/oauth/token?client_id={}&client_secret={}&code={}&grant_type=authorization_code&\
-             redirect_uri={}",
-            self.base, self.client_id, self.client_secret, code, self.redirect
-        );
+        let token = self.access_token(code)?;
+        self.mastodon(token)
+    }
+
+    /// Exchange the code provided by the authorisation url for an access
+    /// token, without creating a client from it.
+    pub fn access_token(&self, code: &str) -> Result<AccessToken> {
+        let mut form = vec![
+            ("client_id", &self.client_id[..]),
+            ("client_secret", &self.client_secret[..]),
+            ("code", code),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", &self.redirect[..]),
+        ];
+        if let Some(ref code_verifier) = self.code_verifier {
+            form.push(("code_verifier", code_verifier));
+        }
+        self.request_token(&form)
+    }
+
+    /// Create an app-level access token from the client id and client
+    /// secret, without a user having to authorize the app.
+    ///
+    /// The resulting client can only access public data and the app's own
+    /// resources, which is enough for e.g. reading public timelines or
+    /// calling `verify_app_credentials`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate elefren;
+    /// # fn main() -> elefren::Result<()> {
+    /// use elefren::prelude::*;
+    ///
+    /// let registration = Registration::new("https://mastodon.social")
+    ///     .client_name("elefren_test")
+    ///     .build()?;
+    /// let mastodon = registration.client_credentials()?;
+    ///
+    /// println!("{:?}", mastodon.verify_app_credentials()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client_credentials(&self) -> Result<Mastodon> {
+        let scopes = self.scopes.to_string();
+        let form = [
+            ("client_id", &self.client_id[..]),
+            ("client_secret", &self.client_secret[..]),
+            ("grant_type", "client_credentials"),
+            ("redirect_uri", &self.redirect[..]),
+            ("scope", &scopes[..]),
+        ];
+
+        let token = self.request_token(&form)?;
+        self.mastodon(token)
+    }
 
+    fn request_token(&self, form: &[(&str, &str)]) -> Result<AccessToken> {
+        let url = format!("{}/oauth/token", self.base);
+        let response = self.send(self.client.post(&url).form(form))?;
+        let status = response.status();
         let handle = tokio::runtime::Handle::current();
-        let token: AccessToken = handle.block_on(self.send(self.client.post(&url))?.json())?;
+        let body = handle.block_on(response.bytes())?;
+        parse_token_response(status, &body)
+    }
 
+    fn mastodon(&self, token: AccessToken) -> Result<Mastodon> {
+        let scopes = token.granted_scopes(&self.scopes);
         let data = Data {
             base: self.base.clone().into(),
             client_id: self.client_id.clone().into(),
             client_secret: self.client_secret.clone().into(),
             redirect: self.redirect.clone().into(),
             token: token.access_token.into(),
+            scopes,
         };
 
         let mut builder = MastodonBuilder::new();
@@ -340,6 +578,8 @@ pub struct Registered {
     redirect: String,
     scopes: Scopes,
     force_login: bool,
+    state: String,
+    code_verifier: Option<String>,
 }
 
 #[cfg(test)]
@@ -405,6 +645,172 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_pkce() {
+        let mut r = Registration::new("https://example.com");
+        assert!(r.pkce);
+        r.pkce(false);
+        assert!(!r.pkce);
+    }
+
+    #[test]
+    fn test_code_challenge() {
+        // example from RFC 7636, appendix B
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_generated_parameters() {
+        let state = generate_state();
+        assert_eq!(state.len(), 32);
+        assert_ne!(state, generate_state());
+        let code_verifier = generate_code_verifier();
+        assert_eq!(code_verifier.len(), 64);
+        assert!(code_verifier.chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn test_verify_state() {
+        assert!(verify_state("abc", "abc").is_ok());
+        assert!(matches!(
+            verify_state("abc", "abd"),
+            Err(Error::StateMismatch)
+        ));
+        assert!(matches!(
+            verify_state("abc", "abcd"),
+            Err(Error::StateMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let registered = Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            DEFAULT_REDIRECT_URI,
+            Scopes::read_all(),
+            false,
+        )
+        .with_state("the-state")
+        .with_code_verifier(Some(
+            "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+        ));
+        assert_eq!(
+            registered.authorize_url().expect("Couldn't build url"),
+            "https://example.com/oauth/authorize?client_id=the-client-id&redirect_uri=urn%3Aietf%\
+             3Awg%3Aoauth%3A2.0%3Aoob&scope=read&response_type=code&force_login=false&state=the-\
+             state&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&\
+             code_challenge_method=S256"
+        );
+
+        let registered = registered.with_code_verifier(None);
+        let url = registered.authorize_url().expect("Couldn't build url");
+        assert!(url.ends_with("&state=the-state"));
+    }
+
+    #[test]
+    fn test_parse_token_response() {
+        let body = br#"{
+            "access_token": "ZA-Yj3aBD8U8Cm7lKUp-lm9O9BmDgdhHzDeqsY8tlL0",
+            "token_type": "Bearer",
+            "scope": "read write follow push",
+            "created_at": 1573979017
+        }"#;
+        let token = parse_token_response(reqwest::StatusCode::OK, body)
+            .expect("Couldn't parse token response");
+        assert_eq!(token.token_type, "Bearer");
+        assert_eq!(
+            token.created_at,
+            Some(Utc.timestamp_opt(1573979017, 0).unwrap())
+        );
+        assert_eq!(
+            token.granted_scopes(&Scopes::read_all()),
+            Some(Scopes::all())
+        );
+    }
+
+    #[test]
+    fn test_granted_scopes_default_to_requested() {
+        let body = br#"{"access_token": "abc", "token_type": "Bearer"}"#;
+        let token = parse_token_response(reqwest::StatusCode::OK, body)
+            .expect("Couldn't parse token response");
+        assert_eq!(token.created_at, None);
+        assert_eq!(
+            token.granted_scopes(&Scopes::read_all()),
+            Some(Scopes::read_all())
+        );
+    }
+
+    #[test]
+    fn test_parse_token_error_response() {
+        let body = br#"{
+            "error": "invalid_grant",
+            "error_description": "The provided authorization grant is invalid, expired, revoked, does not match the redirection URI used in the authorization request, or was issued to another client."
+        }"#;
+        match parse_token_response(reqwest::StatusCode::BAD_REQUEST, body) {
+            Err(Error::OAuth(e)) => assert_eq!(e.error, "invalid_grant"),
+            other => panic!("expected an oauth error, got {:?}", other),
+        }
+
+        match parse_token_response(reqwest::StatusCode::BAD_GATEWAY, b"<html></html>") {
+            Err(Error::Server(status)) => assert_eq!(status, reqwest::StatusCode::BAD_GATEWAY),
+            other => panic!("expected a server error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parts_round_trip() {
+        let registered = Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            DEFAULT_REDIRECT_URI,
+            Scopes::read_all(),
+            false,
+        )
+        .with_code_verifier(Some(generate_code_verifier()));
+        let url = registered.authorize_url().expect("Couldn't build url");
+        let state = registered.state().to_string();
+        let code_verifier = registered.code_verifier().map(String::from);
+
+        let (base, client_id, client_secret, redirect, scopes, force_login) =
+            registered.into_parts();
+        let restored = Registered::from_parts(
+            &base,
+            &client_id,
+            &client_secret,
+            &redirect,
+            scopes,
+            force_login,
+        )
+        .with_state(state)
+        .with_code_verifier(code_verifier.clone());
+
+        assert_eq!(restored.authorize_url().expect("Couldn't build url"), url);
+        assert_eq!(restored.code_verifier().map(String::from), code_verifier);
+    }
+
+    #[test]
+    fn test_from_parts_without_pkce() {
+        let registered = Registered::from_parts(
+            "https://example.com",
+            "the-client-id",
+            "the-client-secret",
+            DEFAULT_REDIRECT_URI,
+            Scopes::read_all(),
+            false,
+        );
+        assert_eq!(registered.code_verifier(), None);
+        assert!(!registered
+            .authorize_url()
+            .expect("Couldn't build url")
+            .contains("code_challenge"));
+    }
+
     #[test]
     fn test_default_redirect_uri() {
         assert_eq!(&default_redirect_uri()[..], DEFAULT_REDIRECT_URI);
@@ -0,0 +1,347 @@
+use crate::errors::Error;
+use serde::Serialize;
+use url::form_urlencoded;
+
+/// Where the accounts listed by `admin_accounts_v2` come from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountOrigin {
+    /// Only local accounts
+    Local,
+    /// Only remote accounts
+    Remote,
+}
+
+/// The moderation state of the accounts listed by `admin_accounts_v2`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountStatus {
+    /// Accounts that are in good standing
+    Active,
+    /// Accounts that are waiting for approval
+    Pending,
+    /// Accounts that have been disabled
+    Disabled,
+    /// Accounts that have been silenced
+    Silenced,
+    /// Accounts that have been suspended
+    Suspended,
+}
+
+/// Builder for making a client.admin_accounts_v2() call
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::requests::{AccountStatus, AdminAccountsRequest};
+///
+/// let request = AdminAccountsRequest::new()
+///     .status(AccountStatus::Pending)
+///     .role_id("3")
+///     .limit(10);
+/// # assert_eq!(&request.to_querystring().expect("Couldn't serialize qs")[..], "?status=pending&limit=10&role_ids%5B%5D=3");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AdminAccountsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<AccountOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<AccountStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<String>,
+    #[serde(skip)]
+    role_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invited_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl AdminAccountsRequest {
+    /// Construct a new `AdminAccountsRequest` object
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only list local or remote accounts
+    pub fn origin(mut self, origin: AccountOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Only list accounts with the given moderation state
+    pub fn status(mut self, status: AccountStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Only list accounts with staff permissions
+    pub fn staff(mut self) -> Self {
+        self.permissions = Some("staff".to_string());
+        self
+    }
+
+    /// Only list accounts with the given role. Can be called multiple times
+    /// to match any of several roles.
+    pub fn role_id(mut self, role_id: &str) -> Self {
+        self.role_ids.push(role_id.to_string());
+        self
+    }
+
+    /// Only list accounts that were invited by the given account
+    pub fn invited_by(mut self, account_id: &str) -> Self {
+        self.invited_by = Some(account_id.to_string());
+        self
+    }
+
+    /// Search for the given username
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    /// Search for the given display name
+    pub fn display_name(mut self, display_name: &str) -> Self {
+        self.display_name = Some(display_name.to_string());
+        self
+    }
+
+    /// Only list accounts on the given domain
+    pub fn by_domain(mut self, domain: &str) -> Self {
+        self.by_domain = Some(domain.to_string());
+        self
+    }
+
+    /// Search for the given email address
+    pub fn email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    /// Only list accounts that have used the given IP address
+    pub fn ip(mut self, ip: &str) -> Self {
+        self.ip = Some(ip.to_string());
+        self
+    }
+
+    /// Set the `?max_id=:max_id` flag for the .admin_accounts_v2() request
+    pub fn max_id(mut self, max_id: &str) -> Self {
+        self.max_id = Some(max_id.to_string());
+        self
+    }
+
+    /// Set the `?since_id=:since_id` flag for the .admin_accounts_v2()
+    /// request
+    pub fn since_id(mut self, since_id: &str) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    /// Set the `?min_id=:min_id` flag for the .admin_accounts_v2() request
+    pub fn min_id(mut self, min_id: &str) -> Self {
+        self.min_id = Some(min_id.to_string());
+        self
+    }
+
+    /// Set the `?limit=:limit` flag for the .admin_accounts_v2() request
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Serialize into a query string
+    pub fn to_querystring(&self) -> Result<String, Error> {
+        let mut qs = serde_qs::to_string(&self)?;
+
+        let mut role_ids = form_urlencoded::Serializer::new(String::new());
+        for role_id in &self.role_ids {
+            role_ids.append_pair("role_ids[]", role_id);
+        }
+        let role_ids = role_ids.finish();
+
+        if !role_ids.is_empty() {
+            if !qs.is_empty() {
+                qs += "&";
+            }
+            qs += &role_ids;
+        }
+
+        Ok(format!("?{}", qs))
+    }
+}
+
+/// The action to take against an account
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdminAccountAction {
+    /// Send a warning without taking any other action
+    #[serde(rename = "none")]
+    Warn,
+    /// Force the account's media to be marked as sensitive
+    Sensitive,
+    /// Prevent the account from logging in
+    Disable,
+    /// Hide the account from the public
+    Silence,
+    /// Suspend the account
+    Suspend,
+}
+
+/// Form used to perform a moderation action against an account
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::requests::{AdminAccountAction, AdminActionRequest};
+///
+/// let request = AdminActionRequest::new(AdminAccountAction::Silence)
+///     .report_id("1")
+///     .text("Please stop")
+///     .send_email_notification(false);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AdminActionRequest {
+    #[serde(rename = "type")]
+    action: AdminAccountAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning_preset_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_email_notification: Option<bool>,
+}
+
+impl AdminActionRequest {
+    /// Create a new AdminActionRequest
+    pub fn new(action: AdminAccountAction) -> AdminActionRequest {
+        AdminActionRequest {
+            action,
+            report_id: None,
+            warning_preset_id: None,
+            text: None,
+            send_email_notification: None,
+        }
+    }
+
+    /// Resolve the given report as part of this action
+    pub fn report_id(mut self, report_id: &str) -> Self {
+        self.report_id = Some(report_id.to_string());
+        self
+    }
+
+    /// Use the given warning preset
+    pub fn warning_preset_id(mut self, warning_preset_id: &str) -> Self {
+        self.warning_preset_id = Some(warning_preset_id.to_string());
+        self
+    }
+
+    /// Additional clarification for why this action was taken
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Set whether the user should be notified by email
+    pub fn send_email_notification(mut self, send_email_notification: bool) -> Self {
+        self.send_email_notification = Some(send_email_notification);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_accounts_request_new() {
+        let request = AdminAccountsRequest::new();
+        assert_eq!(request.to_querystring().expect("Couldn't serialize"), "?");
+    }
+
+    #[test]
+    fn test_accounts_request_querystring() {
+        let request = AdminAccountsRequest::new()
+            .origin(AccountOrigin::Remote)
+            .staff()
+            .by_domain("example.com")
+            .role_id("1")
+            .role_id("2")
+            .max_id("foo");
+        assert_eq!(
+            request.to_querystring().expect("Couldn't serialize"),
+            "?origin=remote&permissions=staff&by_domain=example.com&max_id=foo&role_ids%5B%5D=1&\
+             role_ids%5B%5D=2"
+        );
+    }
+
+    #[test]
+    fn test_role_ids_only() {
+        let request = AdminAccountsRequest::new().role_id("1");
+        assert_eq!(
+            request.to_querystring().expect("Couldn't serialize"),
+            "?role_ids%5B%5D=1"
+        );
+    }
+
+    #[test]
+    fn test_role_ids_are_encoded() {
+        let request = AdminAccountsRequest::new().role_id("1&permissions=staff");
+        assert_eq!(
+            request.to_querystring().expect("Couldn't serialize"),
+            "?role_ids%5B%5D=1%26permissions%3Dstaff"
+        );
+    }
+
+    #[test]
+    fn test_action_new() {
+        let request = AdminActionRequest::new(AdminAccountAction::Disable);
+        assert_eq!(
+            request,
+            AdminActionRequest {
+                action: AdminAccountAction::Disable,
+                report_id: None,
+                warning_preset_id: None,
+                text: None,
+                send_email_notification: None,
+            }
+        )
+    }
+
+    #[test]
+    fn test_serialize_action() {
+        let request = AdminActionRequest::new(AdminAccountAction::Warn);
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(ser, r#"{"type":"none"}"#);
+
+        let request = AdminActionRequest::new(AdminAccountAction::Suspend)
+            .report_id("7")
+            .text("spam")
+            .send_email_notification(true);
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(
+            ser,
+            r#"{"type":"suspend","report_id":"7","text":"spam","send_email_notification":true}"#
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::entities::filter::FilterContext;
+use super::serialize_duration;
+use crate::entities::filter::{FilterAction, FilterContext};
 use serde::Serialize;
 use std::time::Duration;
 
@@ -56,21 +57,81 @@ impl AddFilterRequest {
     }
 }
 
-mod serialize_duration {
-    use serde::ser::Serializer;
-    use std::time::Duration;
+/// Form used to create or update a filter with the v2 filters API
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::{
+///     entities::filter::{FilterAction, FilterContext},
+///     requests::FilterRequest,
+/// };
+///
+/// let request = FilterRequest::new("spoilers")
+///     .context(FilterContext::Home)
+///     .context(FilterContext::Public)
+///     .filter_action(FilterAction::Warn)
+///     .keyword("finale", true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterRequest {
+    title: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    context: Vec<FilterContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter_action: Option<FilterAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_duration::ser")]
+    expires_in: Option<Duration>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    keywords_attributes: Vec<KeywordAttributes>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct KeywordAttributes {
+    keyword: String,
+    whole_word: bool,
+}
 
-    pub(crate) fn ser<S>(duration: &Option<Duration>, s: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        if let Some(d) = duration {
-            let sec = d.as_secs();
-            s.serialize_u64(sec)
-        } else {
-            s.serialize_none()
+impl FilterRequest {
+    /// Create a new FilterRequest
+    pub fn new(title: &str) -> FilterRequest {
+        FilterRequest {
+            title: title.to_string(),
+            context: Vec::new(),
+            filter_action: None,
+            expires_in: None,
+            keywords_attributes: Vec::new(),
         }
     }
+
+    /// Add a context in which the filter should be applied
+    pub fn context(mut self, context: FilterContext) -> Self {
+        self.context.push(context);
+        self
+    }
+
+    /// Set the action to be taken when a status matches the filter
+    pub fn filter_action(mut self, filter_action: FilterAction) -> Self {
+        self.filter_action = Some(filter_action);
+        self
+    }
+
+    /// Set `expires_in` to a duration
+    pub fn expires_in(mut self, d: Duration) -> Self {
+        self.expires_in = Some(d);
+        self
+    }
+
+    /// Add a keyword to the filter
+    pub fn keyword(mut self, keyword: &str, whole_word: bool) -> Self {
+        self.keywords_attributes.push(KeywordAttributes {
+            keyword: keyword.to_string(),
+            whole_word,
+        });
+        self
+    }
 }
 
 #[cfg(test)]
@@ -150,4 +211,56 @@ mod tests {
             r#"{"phrase":"foo","context":"home","irreversible":null,"whole_word":null,"expires_in":300}"#
         )
     }
+
+    #[test]
+    fn test_filter_request_new() {
+        let request = FilterRequest::new("foo");
+        assert_eq!(
+            request,
+            FilterRequest {
+                title: "foo".to_string(),
+                context: vec![],
+                filter_action: None,
+                expires_in: None,
+                keywords_attributes: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn test_filter_request_keyword() {
+        let request = FilterRequest::new("foo").keyword("bar", true);
+        assert_eq!(
+            request,
+            FilterRequest {
+                title: "foo".to_string(),
+                context: vec![],
+                filter_action: None,
+                expires_in: None,
+                keywords_attributes: vec![KeywordAttributes {
+                    keyword: "bar".to_string(),
+                    whole_word: true,
+                }],
+            }
+        )
+    }
+
+    #[test]
+    fn test_serialize_filter_request() {
+        let request = FilterRequest::new("foo");
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(ser, r#"{"title":"foo"}"#);
+
+        let request = FilterRequest::new("foo")
+            .context(FilterContext::Home)
+            .context(FilterContext::Thread)
+            .filter_action(FilterAction::Hide)
+            .expires_in(Duration::from_secs(300))
+            .keyword("bar", false);
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(
+            ser,
+            r#"{"title":"foo","context":["home","thread"],"filter_action":"hide","expires_in":300,"keywords_attributes":[{"keyword":"bar","whole_word":false}]}"#
+        );
+    }
 }
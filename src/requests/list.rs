@@ -0,0 +1,108 @@
+use crate::entities::list::RepliesPolicy;
+use serde::Serialize;
+
+/// Form used to create or update a list
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::{entities::list::RepliesPolicy, requests::ListRequest};
+///
+/// let request = ListRequest::new("friends")
+///     .replies_policy(RepliesPolicy::List)
+///     .exclusive(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListRequest {
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replies_policy: Option<RepliesPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclusive: Option<bool>,
+}
+
+impl ListRequest {
+    /// Create a new ListRequest
+    pub fn new(title: &str) -> ListRequest {
+        ListRequest {
+            title: title.to_string(),
+            replies_policy: None,
+            exclusive: None,
+        }
+    }
+
+    /// Set which replies should be shown in the list
+    pub fn replies_policy(mut self, replies_policy: RepliesPolicy) -> Self {
+        self.replies_policy = Some(replies_policy);
+        self
+    }
+
+    /// Set whether members of the list should be removed from the home
+    /// timeline
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = Some(exclusive);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_new() {
+        let request = ListRequest::new("foo");
+        assert_eq!(
+            request,
+            ListRequest {
+                title: "foo".to_string(),
+                replies_policy: None,
+                exclusive: None,
+            }
+        )
+    }
+
+    #[test]
+    fn test_replies_policy() {
+        let request = ListRequest::new("foo").replies_policy(RepliesPolicy::Followed);
+        assert_eq!(
+            request,
+            ListRequest {
+                title: "foo".to_string(),
+                replies_policy: Some(RepliesPolicy::Followed),
+                exclusive: None,
+            }
+        )
+    }
+
+    #[test]
+    fn test_exclusive() {
+        let request = ListRequest::new("foo").exclusive(true);
+        assert_eq!(
+            request,
+            ListRequest {
+                title: "foo".to_string(),
+                replies_policy: None,
+                exclusive: Some(true),
+            }
+        )
+    }
+
+    #[test]
+    fn test_serialize_request() {
+        let request = ListRequest::new("foo");
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(ser, r#"{"title":"foo"}"#);
+
+        let request = ListRequest::new("foo")
+            .replies_policy(RepliesPolicy::None)
+            .exclusive(false);
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(
+            ser,
+            r#"{"title":"foo","replies_policy":"none","exclusive":false}"#
+        );
+    }
+}
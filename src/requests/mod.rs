@@ -1,16 +1,77 @@
+/// Data structures for the MastodonClient::admin_accounts_v2 and
+/// MastodonClient::admin_account_action methods
+pub use self::admin::{
+    AccountOrigin,
+    AccountStatus,
+    AdminAccountAction,
+    AdminAccountsRequest,
+    AdminActionRequest,
+};
 /// Data structure for the MastodonClient::directory method
 pub use self::directory::DirectoryRequest;
-/// Data structure for the MastodonClient::add_filter method
-pub use self::filter::AddFilterRequest;
+/// Data structures for the MastodonClient::add_filter and
+/// MastodonClient::add_filter_v2 methods
+pub use self::filter::{AddFilterRequest, FilterRequest};
+/// Data structure for the MastodonClient::create_list and
+/// MastodonClient::update_list methods
+pub use self::list::ListRequest;
+/// Data structure for the MastodonClient::notifications method
+pub use self::notifications::NotificationsRequest;
 /// Data structure for the MastodonClient::add_push_subscription method
 pub use self::push::{AddPushRequest, Keys, UpdatePushRequest};
+/// Data structures for the MastodonClient::follow and MastodonClient::mute
+/// methods
+pub use self::relationship::{FollowOptions, MuteOptions};
+/// Data structure for the MastodonClient::report method
+pub use self::report::ReportRequest;
 /// Data structure for the MastodonClient::statuses method
 pub use self::statuses::StatusesRequest;
+/// Data structure for the timeline methods of MastodonClient
+pub use self::timeline::TimelineRequest;
 /// Data structure for the MastodonClient::update_credentials method
 pub use self::update_credentials::UpdateCredsRequest;
 
+mod admin;
 mod directory;
 mod filter;
+mod list;
+mod notifications;
 mod push;
+mod relationship;
+mod report;
 mod statuses;
+mod timeline;
 mod update_credentials;
+
+mod bool_qs_serialize {
+    use serde::Serializer;
+
+    pub fn is_false(b: &bool) -> bool {
+        !*b
+    }
+
+    pub fn serialize<S: Serializer>(b: &bool, s: S) -> Result<S::Ok, S::Error> {
+        if *b {
+            s.serialize_i64(1)
+        } else {
+            s.serialize_i64(0)
+        }
+    }
+}
+
+mod serialize_duration {
+    use serde::ser::Serializer;
+    use std::time::Duration;
+
+    pub(crate) fn ser<S>(duration: &Option<Duration>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(d) = duration {
+            let sec = d.as_secs();
+            s.serialize_u64(sec)
+        } else {
+            s.serialize_none()
+        }
+    }
+}
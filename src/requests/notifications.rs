@@ -0,0 +1,173 @@
+use crate::{entities::notification::NotificationType, errors::Error};
+use serde::Serialize;
+use std::borrow::Cow;
+use url::form_urlencoded;
+
+/// Builder for making a client.notifications() call
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// # use elefren::{entities::notification::NotificationType, requests::NotificationsRequest};
+/// let request = NotificationsRequest::new()
+///     .types(&[NotificationType::Mention])
+///     .limit(40);
+/// # assert_eq!(&request.to_querystring().expect("Couldn't serialize qs")[..], "?limit=40&types%5B%5D=mention");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct NotificationsRequest<'a> {
+    #[serde(skip)]
+    types: Vec<NotificationType>,
+    #[serde(skip)]
+    exclude_types: Vec<NotificationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl<'a> NotificationsRequest<'a> {
+    /// Construct a new `NotificationsRequest` object
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return notifications of the given types (`?types[]=:type`)
+    pub fn types(mut self, types: &[NotificationType]) -> Self {
+        self.types.extend_from_slice(types);
+        self
+    }
+
+    /// Exclude notifications of the given types (`?exclude_types[]=:type`)
+    pub fn exclude_types(mut self, types: &[NotificationType]) -> Self {
+        self.exclude_types.extend_from_slice(types);
+        self
+    }
+
+    /// Only return notifications received from the given account
+    /// (`?account_id=:account_id`)
+    pub fn account_id<S: Into<Cow<'a, str>>>(mut self, account_id: S) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Set the `?max_id=:max_id` flag for the .notifications() request
+    pub fn max_id<S: Into<Cow<'a, str>>>(mut self, max_id: S) -> Self {
+        self.max_id = Some(max_id.into());
+        self
+    }
+
+    /// Set the `?since_id=:since_id` flag for the .notifications() request
+    pub fn since_id<S: Into<Cow<'a, str>>>(mut self, since_id: S) -> Self {
+        self.since_id = Some(since_id.into());
+        self
+    }
+
+    /// Set the `?min_id=:min_id` flag for the .notifications() request
+    pub fn min_id<S: Into<Cow<'a, str>>>(mut self, min_id: S) -> Self {
+        self.min_id = Some(min_id.into());
+        self
+    }
+
+    /// Set the `?limit=:limit` flag for the .notifications() request
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Turns this builder into a querystring
+    pub fn to_querystring(&self) -> Result<String, Error> {
+        let mut qs = serde_qs::to_string(&self)?;
+
+        let mut arrays = form_urlencoded::Serializer::new(String::new());
+        for t in &self.types {
+            arrays.append_pair("types[]", &t.to_string());
+        }
+        for t in &self.exclude_types {
+            arrays.append_pair("exclude_types[]", &t.to_string());
+        }
+        let arrays = arrays.finish();
+
+        if !arrays.is_empty() {
+            if !qs.is_empty() {
+                qs += "&";
+            }
+            qs += &arrays;
+        }
+
+        Ok(format!("?{}", qs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let request = NotificationsRequest::new();
+        assert_eq!(
+            request,
+            NotificationsRequest {
+                types: vec![],
+                exclude_types: vec![],
+                account_id: None,
+                max_id: None,
+                since_id: None,
+                min_id: None,
+                limit: None,
+            }
+        );
+        assert_eq!(&request.to_querystring().expect("Couldn't serialize"), "?");
+    }
+
+    #[test]
+    fn test_types() {
+        let request =
+            NotificationsRequest::new().types(&[NotificationType::Mention, NotificationType::Poll]);
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?types%5B%5D=mention&types%5B%5D=poll"
+        );
+    }
+
+    #[test]
+    fn test_exclude_types() {
+        let request = NotificationsRequest::new()
+            .exclude_types(&[NotificationType::Favourite, NotificationType::AdminSignUp]);
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?exclude_types%5B%5D=favourite&exclude_types%5B%5D=admin.sign_up"
+        );
+    }
+
+    #[test]
+    fn test_account_id() {
+        let request = NotificationsRequest::new().account_id("42").since_id("7");
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?account_id=42&since_id=7"
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        let request = NotificationsRequest::new()
+            .types(&[NotificationType::Mention])
+            .exclude_types(&[NotificationType::Unknown("quote".to_string())])
+            .max_id("10")
+            .min_id("1")
+            .limit(5);
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?max_id=10&min_id=1&limit=5&types%5B%5D=mention&exclude_types%5B%5D=quote"
+        );
+    }
+}
@@ -53,6 +53,7 @@ impl Keys {
 /// #   client_secret: "".into(),
 /// #   redirect: "".into(),
 /// #   token: "".into(),
+/// #   scopes: None,
 /// # };
 /// use elefren::requests::{AddPushRequest, Keys};
 ///
@@ -227,6 +228,7 @@ impl AddPushRequest {
 /// #   client_secret: "".into(),
 /// #   redirect: "".into(),
 /// #   token: "".into(),
+/// #   scopes: None,
 /// # };
 /// use elefren::requests::UpdatePushRequest;
 ///
@@ -0,0 +1,153 @@
+use super::serialize_duration;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Options for following an account
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::requests::FollowOptions;
+///
+/// let options = FollowOptions::new()
+///     .reblogs(false)
+///     .notify(true)
+///     .languages(&["en", "de"]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct FollowOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reblogs: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    languages: Option<Vec<String>>,
+}
+
+impl FollowOptions {
+    /// Create a new FollowOptions
+    pub fn new() -> FollowOptions {
+        FollowOptions::default()
+    }
+
+    /// Set whether the account's reblogs should show up in the home timeline
+    pub fn reblogs(mut self, reblogs: bool) -> Self {
+        self.reblogs = Some(reblogs);
+        self
+    }
+
+    /// Set whether to get a notification when the account posts a status
+    pub fn notify(mut self, notify: bool) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Only show statuses in these languages (ISO 639-1 codes) in the home
+    /// timeline
+    pub fn languages<S: std::fmt::Display, I: IntoIterator<Item = S>>(
+        mut self,
+        languages: I,
+    ) -> Self {
+        self.languages = Some(languages.into_iter().map(|s| s.to_string()).collect());
+        self
+    }
+}
+
+/// Options for muting an account
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::requests::MuteOptions;
+/// use std::time::Duration;
+///
+/// let options = MuteOptions::new()
+///     .notifications(false)
+///     .duration(Duration::from_secs(60 * 60 * 24));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+pub struct MuteOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notifications: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_duration::ser")]
+    duration: Option<Duration>,
+}
+
+impl MuteOptions {
+    /// Create a new MuteOptions
+    pub fn new() -> MuteOptions {
+        MuteOptions::default()
+    }
+
+    /// Set whether notifications from the account should be muted as well
+    pub fn notifications(mut self, notifications: bool) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// Set how long the mute should last
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_follow_options_new() {
+        let options = FollowOptions::new();
+        assert_eq!(
+            options,
+            FollowOptions {
+                reblogs: None,
+                notify: None,
+                languages: None,
+            }
+        );
+        let ser = serde_json::to_string(&options).expect("Couldn't serialize");
+        assert_eq!(ser, "{}");
+    }
+
+    #[test]
+    fn test_serialize_follow_options() {
+        let options = FollowOptions::new()
+            .reblogs(false)
+            .notify(true)
+            .languages(["en", "de"]);
+        let ser = serde_json::to_string(&options).expect("Couldn't serialize");
+        assert_eq!(
+            ser,
+            r#"{"reblogs":false,"notify":true,"languages":["en","de"]}"#
+        );
+    }
+
+    #[test]
+    fn test_mute_options_new() {
+        let options = MuteOptions::new();
+        assert_eq!(
+            options,
+            MuteOptions {
+                notifications: None,
+                duration: None,
+            }
+        );
+        let ser = serde_json::to_string(&options).expect("Couldn't serialize");
+        assert_eq!(ser, "{}");
+    }
+
+    #[test]
+    fn test_serialize_mute_options() {
+        let options = MuteOptions::new()
+            .notifications(false)
+            .duration(Duration::from_secs(3600));
+        let ser = serde_json::to_string(&options).expect("Couldn't serialize");
+        assert_eq!(ser, r#"{"notifications":false,"duration":3600}"#);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::entities::report::ReportCategory;
+use serde::Serialize;
+
+/// Form used to report an account
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// use elefren::{entities::report::ReportCategory, requests::ReportRequest};
+///
+/// let request = ReportRequest::new("42")
+///     .status_ids(&["1", "2"])
+///     .comment("spamming the local timeline")
+///     .category(ReportCategory::Violation)
+///     .rule_ids(&["3"])
+///     .forward(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportRequest {
+    account_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    status_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forward: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<ReportCategory>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rule_ids: Vec<String>,
+}
+
+impl ReportRequest {
+    /// Create a new ReportRequest for the given account
+    pub fn new(account_id: &str) -> ReportRequest {
+        ReportRequest {
+            account_id: account_id.to_string(),
+            status_ids: Vec::new(),
+            comment: None,
+            forward: None,
+            category: None,
+            rule_ids: Vec::new(),
+        }
+    }
+
+    /// Attach statuses to the report for additional context
+    pub fn status_ids<S: std::fmt::Display, I: IntoIterator<Item = S>>(mut self, ids: I) -> Self {
+        let ids = ids.into_iter().map(|s| s.to_string());
+        self.status_ids.extend(ids);
+        self
+    }
+
+    /// Set the reason for the report
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Set whether the report should be forwarded to the remote instance of
+    /// the account
+    pub fn forward(mut self, forward: bool) -> Self {
+        self.forward = Some(forward);
+        self
+    }
+
+    /// Set the generic reason for the report
+    pub fn category(mut self, category: ReportCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Cite the rules of the instance that were violated, for the
+    /// `ReportCategory::Violation` category
+    pub fn rule_ids<S: std::fmt::Display, I: IntoIterator<Item = S>>(mut self, ids: I) -> Self {
+        self.rule_ids.extend(ids.into_iter().map(|s| s.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_new() {
+        let request = ReportRequest::new("42");
+        assert_eq!(
+            request,
+            ReportRequest {
+                account_id: "42".to_string(),
+                status_ids: vec![],
+                comment: None,
+                forward: None,
+                category: None,
+                rule_ids: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn test_category() {
+        let request = ReportRequest::new("42").category(ReportCategory::Spam);
+        assert_eq!(
+            request,
+            ReportRequest {
+                account_id: "42".to_string(),
+                status_ids: vec![],
+                comment: None,
+                forward: None,
+                category: Some(ReportCategory::Spam),
+                rule_ids: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn test_serialize_request() {
+        let request = ReportRequest::new("42");
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(ser, r#"{"account_id":"42"}"#);
+
+        let request = ReportRequest::new("42")
+            .status_ids(["1", "2"])
+            .comment("foo")
+            .forward(true)
+            .category(ReportCategory::Violation)
+            .rule_ids(["3"]);
+        let ser = serde_json::to_string(&request).expect("Couldn't serialize");
+        assert_eq!(
+            ser,
+            r#"{"account_id":"42","status_ids":["1","2"],"comment":"foo","forward":true,"category":"violation","rule_ids":["3"]}"#
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use super::bool_qs_serialize;
+use crate::errors::Error;
+use serde::Serialize;
+use std::borrow::Cow;
+use url::form_urlencoded;
+
+/// Builder for making a call to one of the timeline methods
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// # use elefren::requests::TimelineRequest;
+/// let request = TimelineRequest::new()
+///     .only_media()
+///     .any_tag("cats")
+///     .limit(10);
+/// # assert_eq!(&request.to_querystring().expect("Couldn't serialize qs")[..], "?only_media=1&limit=10&any%5B%5D=cats");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TimelineRequest<'a> {
+    #[serde(skip_serializing_if = "bool_qs_serialize::is_false")]
+    #[serde(serialize_with = "bool_qs_serialize::serialize")]
+    local: bool,
+    #[serde(skip_serializing_if = "bool_qs_serialize::is_false")]
+    #[serde(serialize_with = "bool_qs_serialize::serialize")]
+    remote: bool,
+    #[serde(skip_serializing_if = "bool_qs_serialize::is_false")]
+    #[serde(serialize_with = "bool_qs_serialize::serialize")]
+    only_media: bool,
+    #[serde(skip)]
+    any: Vec<Cow<'a, str>>,
+    #[serde(skip)]
+    all: Vec<Cow<'a, str>>,
+    #[serde(skip)]
+    none: Vec<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl<'a> TimelineRequest<'a> {
+    /// Construct a new `TimelineRequest` object
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `?local=1` flag, to only show local statuses
+    pub fn local(mut self) -> Self {
+        self.local = true;
+        self
+    }
+
+    /// Set the `?remote=1` flag, to only show remote statuses
+    pub fn remote(mut self) -> Self {
+        self.remote = true;
+        self
+    }
+
+    /// Set the `?only_media=1` flag, to only show statuses with media
+    /// attachments
+    pub fn only_media(mut self) -> Self {
+        self.only_media = true;
+        self
+    }
+
+    /// Also include statuses with this tag in a hashtag timeline
+    /// (`?any[]=:tag`)
+    pub fn any_tag<S: Into<Cow<'a, str>>>(mut self, tag: S) -> Self {
+        self.any.push(tag.into());
+        self
+    }
+
+    /// Only include statuses that also have this tag in a hashtag timeline
+    /// (`?all[]=:tag`)
+    pub fn all_tag<S: Into<Cow<'a, str>>>(mut self, tag: S) -> Self {
+        self.all.push(tag.into());
+        self
+    }
+
+    /// Exclude statuses with this tag from a hashtag timeline
+    /// (`?none[]=:tag`)
+    pub fn none_tag<S: Into<Cow<'a, str>>>(mut self, tag: S) -> Self {
+        self.none.push(tag.into());
+        self
+    }
+
+    /// Set the `?max_id=:max_id` flag for the timeline request
+    pub fn max_id<S: Into<Cow<'a, str>>>(mut self, max_id: S) -> Self {
+        self.max_id = Some(max_id.into());
+        self
+    }
+
+    /// Set the `?since_id=:since_id` flag for the timeline request
+    pub fn since_id<S: Into<Cow<'a, str>>>(mut self, since_id: S) -> Self {
+        self.since_id = Some(since_id.into());
+        self
+    }
+
+    /// Set the `?min_id=:min_id` flag for the timeline request
+    pub fn min_id<S: Into<Cow<'a, str>>>(mut self, min_id: S) -> Self {
+        self.min_id = Some(min_id.into());
+        self
+    }
+
+    /// Set the `?limit=:limit` flag for the timeline request
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Turns this builder into a querystring
+    pub fn to_querystring(&self) -> Result<String, Error> {
+        let mut qs = serde_qs::to_string(&self)?;
+
+        let mut tags = form_urlencoded::Serializer::new(String::new());
+        for tag in &self.any {
+            tags.append_pair("any[]", tag);
+        }
+        for tag in &self.all {
+            tags.append_pair("all[]", tag);
+        }
+        for tag in &self.none {
+            tags.append_pair("none[]", tag);
+        }
+        let tags = tags.finish();
+
+        if !tags.is_empty() {
+            if !qs.is_empty() {
+                qs += "&";
+            }
+            qs += &tags;
+        }
+
+        Ok(format!("?{}", qs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let request = TimelineRequest::new();
+        assert_eq!(
+            request,
+            TimelineRequest {
+                local: false,
+                remote: false,
+                only_media: false,
+                any: vec![],
+                all: vec![],
+                none: vec![],
+                max_id: None,
+                since_id: None,
+                min_id: None,
+                limit: None,
+            }
+        );
+        assert_eq!(&request.to_querystring().expect("Couldn't serialize"), "?");
+    }
+
+    #[test]
+    fn test_flags() {
+        let request = TimelineRequest::new().local().remote().only_media();
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?local=1&remote=1&only_media=1"
+        );
+    }
+
+    #[test]
+    fn test_cursors() {
+        let request = TimelineRequest::new()
+            .max_id("10")
+            .since_id("1")
+            .min_id("2")
+            .limit(20);
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?max_id=10&since_id=1&min_id=2&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_tags() {
+        let request = TimelineRequest::new()
+            .any_tag("cats")
+            .any_tag("dogs")
+            .all_tag("photography")
+            .none_tag("nsfw");
+        assert_eq!(
+            &request.to_querystring().expect("Couldn't serialize"),
+            "?any%5B%5D=cats&any%5B%5D=dogs&all%5B%5D=photography&none%5B%5D=nsfw"
+        );
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
 /// #   client_secret: "".into(),
 /// #   redirect: "".into(),
 /// #   token: "".into(),
+/// #   scopes: None,
 /// # };
 /// use elefren::{prelude::*, status_builder::Visibility, UpdateCredsRequest};
 ///
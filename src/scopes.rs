@@ -202,6 +202,74 @@ impl Scopes {
         Scopes::new(Scope::Push)
     }
 
+    /// Represents the full "admin:read" scope
+    ///
+    /// ```
+    /// # extern crate elefren;
+    /// # use std::error::Error;
+    /// use elefren::scopes::Scopes;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let scope = Scopes::admin_read_all();
+    /// assert_eq!(&format!("{}", scope), "admin:read");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn admin_read_all() -> Scopes {
+        Scopes::new(Scope::AdminRead(None))
+    }
+
+    /// Represents a specific "admin:read:___" scope
+    ///
+    /// ```
+    /// # extern crate elefren;
+    /// # use std::error::Error;
+    /// use elefren::scopes::{AdminRead, Scopes};
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let scope = Scopes::admin_read(AdminRead::Accounts);
+    /// assert_eq!(&format!("{}", scope), "admin:read:accounts");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn admin_read(subscope: AdminRead) -> Scopes {
+        Scopes::new(Scope::AdminRead(Some(subscope)))
+    }
+
+    /// Represents the full "admin:write" scope
+    ///
+    /// ```
+    /// # extern crate elefren;
+    /// # use std::error::Error;
+    /// use elefren::scopes::Scopes;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let scope = Scopes::admin_write_all();
+    /// assert_eq!(&format!("{}", scope), "admin:write");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn admin_write_all() -> Scopes {
+        Scopes::new(Scope::AdminWrite(None))
+    }
+
+    /// Represents a specific "admin:write:___" scope
+    ///
+    /// ```
+    /// # extern crate elefren;
+    /// # use std::error::Error;
+    /// use elefren::scopes::{AdminWrite, Scopes};
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let scope = Scopes::admin_write(AdminWrite::Accounts);
+    /// assert_eq!(&format!("{}", scope), "admin:write:accounts");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn admin_write(subscope: AdminWrite) -> Scopes {
+        Scopes::new(Scope::AdminWrite(Some(subscope)))
+    }
+
     /// Combines 2 scopes together
     ///
     /// # Example
@@ -307,6 +375,12 @@ enum Scope {
     /// Push permissions
     #[serde(rename = "push")]
     Push,
+    /// Read only permissions for the admin API.
+    #[serde(rename = "admin:read")]
+    AdminRead(Option<AdminRead>),
+    /// Write permissions for the admin API.
+    #[serde(rename = "admin:write")]
+    AdminWrite(Option<AdminWrite>),
 }
 
 impl FromStr for Scope {
@@ -318,6 +392,16 @@ impl FromStr for Scope {
             "write" => Scope::Write(None),
             "follow" => Scope::Follow,
             "push" => Scope::Push,
+            "admin:read" => Scope::AdminRead(None),
+            "admin:write" => Scope::AdminWrite(None),
+            admin_read if admin_read.starts_with("admin:read:") => {
+                let r: AdminRead = AdminRead::from_str(&admin_read[11..])?;
+                Scope::AdminRead(Some(r))
+            },
+            admin_write if admin_write.starts_with("admin:write:") => {
+                let w: AdminWrite = AdminWrite::from_str(&admin_write[12..])?;
+                Scope::AdminWrite(Some(w))
+            },
             read if read.starts_with("read:") => {
                 let r: Read = Read::from_str(&read[5..])?;
                 Scope::Read(Some(r))
@@ -331,34 +415,28 @@ impl FromStr for Scope {
     }
 }
 
+impl Scope {
+    // Position of each kind of scope, used to order scopes of different kinds
+    fn rank(&self) -> u8 {
+        match *self {
+            Scope::Read(..) => 0,
+            Scope::Write(..) => 1,
+            Scope::Follow => 2,
+            Scope::Push => 3,
+            Scope::AdminRead(..) => 4,
+            Scope::AdminWrite(..) => 5,
+        }
+    }
+}
+
 impl PartialOrd for Scope {
     fn partial_cmp(&self, other: &Scope) -> Option<Ordering> {
         Some(match (*self, *other) {
-            (Scope::Read(None), Scope::Read(None)) => Ordering::Equal,
-            (Scope::Read(None), Scope::Read(Some(..))) => Ordering::Less,
-            (Scope::Read(Some(..)), Scope::Read(None)) => Ordering::Greater,
-            (Scope::Read(Some(ref a)), Scope::Read(Some(ref b))) => a.cmp(b),
-
-            (Scope::Write(None), Scope::Write(None)) => Ordering::Equal,
-            (Scope::Write(None), Scope::Write(Some(..))) => Ordering::Less,
-            (Scope::Write(Some(..)), Scope::Write(None)) => Ordering::Greater,
-            (Scope::Write(Some(ref a)), Scope::Write(Some(ref b))) => a.cmp(b),
-
-            (Scope::Read(..), Scope::Write(..)) => Ordering::Less,
-            (Scope::Read(..), Scope::Follow) => Ordering::Less,
-            (Scope::Read(..), Scope::Push) => Ordering::Less,
-
-            (Scope::Write(..), Scope::Read(..)) => Ordering::Greater,
-            (Scope::Write(..), Scope::Follow) => Ordering::Less,
-            (Scope::Write(..), Scope::Push) => Ordering::Less,
-
-            (Scope::Follow, Scope::Read(..)) => Ordering::Greater,
-            (Scope::Follow, Scope::Write(..)) => Ordering::Greater,
-            (Scope::Follow, Scope::Follow) => Ordering::Equal,
-            (Scope::Follow, Scope::Push) => Ordering::Less,
-
-            (Scope::Push, Scope::Push) => Ordering::Equal,
-            (Scope::Push, _) => Ordering::Greater,
+            (Scope::Read(ref a), Scope::Read(ref b)) => a.cmp(b),
+            (Scope::Write(ref a), Scope::Write(ref b)) => a.cmp(b),
+            (Scope::AdminRead(ref a), Scope::AdminRead(ref b)) => a.cmp(b),
+            (Scope::AdminWrite(ref a), Scope::AdminWrite(ref b)) => a.cmp(b),
+            (ref a, ref b) => a.rank().cmp(&b.rank()),
         })
     }
 }
@@ -379,6 +457,10 @@ impl fmt::Display for Scope {
             Write(None) => "write",
             Follow => "follow",
             Push => "push",
+            AdminRead(Some(ref r)) => return fmt::Display::fmt(r, f),
+            AdminRead(None) => "admin:read",
+            AdminWrite(Some(ref w)) => return fmt::Display::fmt(w, f),
+            AdminWrite(None) => "admin:write",
         };
         write!(f, "{}", s)
     }
@@ -399,6 +481,9 @@ pub enum Read {
     /// Blocks
     #[serde(rename = "blocks")]
     Blocks,
+    /// Bookmarks
+    #[serde(rename = "bookmarks")]
+    Bookmarks,
     /// Favourites
     #[serde(rename = "favourites")]
     Favourites,
@@ -435,6 +520,7 @@ impl FromStr for Read {
         Ok(match s {
             "accounts" => Read::Accounts,
             "blocks" => Read::Blocks,
+            "bookmarks" => Read::Bookmarks,
             "favourites" => Read::Favourites,
             "filters" => Read::Filters,
             "follows" => Read::Follows,
@@ -471,6 +557,7 @@ impl fmt::Display for Read {
             match *self {
                 Read::Accounts => "accounts",
                 Read::Blocks => "blocks",
+                Read::Bookmarks => "bookmarks",
                 Read::Favourites => "favourites",
                 Read::Filters => "filters",
                 Read::Follows => "follows",
@@ -494,6 +581,9 @@ pub enum Write {
     /// Blocks
     #[serde(rename = "blocks")]
     Blocks,
+    /// Bookmarks
+    #[serde(rename = "bookmarks")]
+    Bookmarks,
     /// Favourites
     #[serde(rename = "favourites")]
     Favourites,
@@ -530,6 +620,7 @@ impl FromStr for Write {
         Ok(match s {
             "accounts" => Write::Accounts,
             "blocks" => Write::Blocks,
+            "bookmarks" => Write::Bookmarks,
             "favourites" => Write::Favourites,
             "filters" => Write::Filters,
             "follows" => Write::Follows,
@@ -566,6 +657,7 @@ impl fmt::Display for Write {
             match *self {
                 Write::Accounts => "accounts",
                 Write::Blocks => "blocks",
+                Write::Bookmarks => "bookmarks",
                 Write::Favourites => "favourites",
                 Write::Filters => "filters",
                 Write::Follows => "follows",
@@ -580,6 +672,160 @@ impl fmt::Display for Write {
     }
 }
 
+/// Represents the granular "admin:read:___" oauth scopes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum AdminRead {
+    /// Accounts
+    #[serde(rename = "accounts")]
+    Accounts,
+    /// Canonical email blocks
+    #[serde(rename = "canonical_email_blocks")]
+    CanonicalEmailBlocks,
+    /// Domain allows
+    #[serde(rename = "domain_allows")]
+    DomainAllows,
+    /// Domain blocks
+    #[serde(rename = "domain_blocks")]
+    DomainBlocks,
+    /// Email domain blocks
+    #[serde(rename = "email_domain_blocks")]
+    EmailDomainBlocks,
+    /// IP blocks
+    #[serde(rename = "ip_blocks")]
+    IpBlocks,
+    /// Reports
+    #[serde(rename = "reports")]
+    Reports,
+}
+
+impl FromStr for AdminRead {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<AdminRead, Self::Err> {
+        Ok(match s {
+            "accounts" => AdminRead::Accounts,
+            "canonical_email_blocks" => AdminRead::CanonicalEmailBlocks,
+            "domain_allows" => AdminRead::DomainAllows,
+            "domain_blocks" => AdminRead::DomainBlocks,
+            "email_domain_blocks" => AdminRead::EmailDomainBlocks,
+            "ip_blocks" => AdminRead::IpBlocks,
+            "reports" => AdminRead::Reports,
+            _ => return Err(Error::Other("Unknown 'admin:read' subcategory".to_string())),
+        })
+    }
+}
+
+impl PartialOrd for AdminRead {
+    fn partial_cmp(&self, other: &AdminRead) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AdminRead {
+    fn cmp(&self, other: &AdminRead) -> Ordering {
+        let a = format!("{:?}", self);
+        let b = format!("{:?}", other);
+        a.cmp(&b)
+    }
+}
+
+impl fmt::Display for AdminRead {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "admin:read:{}",
+            match *self {
+                AdminRead::Accounts => "accounts",
+                AdminRead::CanonicalEmailBlocks => "canonical_email_blocks",
+                AdminRead::DomainAllows => "domain_allows",
+                AdminRead::DomainBlocks => "domain_blocks",
+                AdminRead::EmailDomainBlocks => "email_domain_blocks",
+                AdminRead::IpBlocks => "ip_blocks",
+                AdminRead::Reports => "reports",
+            }
+        )
+    }
+}
+
+/// Represents the granular "admin:write:___" oauth scopes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum AdminWrite {
+    /// Accounts
+    #[serde(rename = "accounts")]
+    Accounts,
+    /// Canonical email blocks
+    #[serde(rename = "canonical_email_blocks")]
+    CanonicalEmailBlocks,
+    /// Domain allows
+    #[serde(rename = "domain_allows")]
+    DomainAllows,
+    /// Domain blocks
+    #[serde(rename = "domain_blocks")]
+    DomainBlocks,
+    /// Email domain blocks
+    #[serde(rename = "email_domain_blocks")]
+    EmailDomainBlocks,
+    /// IP blocks
+    #[serde(rename = "ip_blocks")]
+    IpBlocks,
+    /// Reports
+    #[serde(rename = "reports")]
+    Reports,
+}
+
+impl FromStr for AdminWrite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<AdminWrite, Self::Err> {
+        Ok(match s {
+            "accounts" => AdminWrite::Accounts,
+            "canonical_email_blocks" => AdminWrite::CanonicalEmailBlocks,
+            "domain_allows" => AdminWrite::DomainAllows,
+            "domain_blocks" => AdminWrite::DomainBlocks,
+            "email_domain_blocks" => AdminWrite::EmailDomainBlocks,
+            "ip_blocks" => AdminWrite::IpBlocks,
+            "reports" => AdminWrite::Reports,
+            _ => {
+                return Err(Error::Other(
+                    "Unknown 'admin:write' subcategory".to_string(),
+                ))
+            },
+        })
+    }
+}
+
+impl PartialOrd for AdminWrite {
+    fn partial_cmp(&self, other: &AdminWrite) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AdminWrite {
+    fn cmp(&self, other: &AdminWrite) -> Ordering {
+        let a = format!("{:?}", self);
+        let b = format!("{:?}", other);
+        a.cmp(&b)
+    }
+}
+
+impl fmt::Display for AdminWrite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "admin:write:{}",
+            match *self {
+                AdminWrite::Accounts => "accounts",
+                AdminWrite::CanonicalEmailBlocks => "canonical_email_blocks",
+                AdminWrite::DomainAllows => "domain_allows",
+                AdminWrite::DomainBlocks => "domain_blocks",
+                AdminWrite::EmailDomainBlocks => "email_domain_blocks",
+                AdminWrite::IpBlocks => "ip_blocks",
+                AdminWrite::Reports => "reports",
+            }
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -589,7 +835,8 @@ mod tests {
     fn test_write_cmp() {
         let tests = [
             (Write::Accounts, Write::Blocks),
-            (Write::Blocks, Write::Favourites),
+            (Write::Blocks, Write::Bookmarks),
+            (Write::Bookmarks, Write::Favourites),
             (Write::Favourites, Write::Filters),
             (Write::Filters, Write::Follows),
             (Write::Follows, Write::Lists),
@@ -610,7 +857,8 @@ mod tests {
     fn test_read_cmp() {
         let tests = [
             (Read::Accounts, Read::Blocks),
-            (Read::Blocks, Read::Favourites),
+            (Read::Blocks, Read::Bookmarks),
+            (Read::Bookmarks, Read::Favourites),
             (Read::Favourites, Read::Filters),
             (Read::Filters, Read::Follows),
             (Read::Follows, Read::Lists),
@@ -631,6 +879,7 @@ mod tests {
         let tests = [
             (Scope::Read(None), Scope::Read(Some(Read::Accounts))),
             (Scope::Read(None), Scope::Read(Some(Read::Blocks))),
+            (Scope::Read(None), Scope::Read(Some(Read::Bookmarks))),
             (Scope::Read(None), Scope::Read(Some(Read::Favourites))),
             (Scope::Read(None), Scope::Read(Some(Read::Filters))),
             (Scope::Read(None), Scope::Read(Some(Read::Follows))),
@@ -645,6 +894,7 @@ mod tests {
             (Scope::Read(None), Scope::Push),
             (Scope::Write(None), Scope::Write(Some(Write::Accounts))),
             (Scope::Write(None), Scope::Write(Some(Write::Blocks))),
+            (Scope::Write(None), Scope::Write(Some(Write::Bookmarks))),
             (Scope::Write(None), Scope::Write(Some(Write::Favourites))),
             (Scope::Write(None), Scope::Write(Some(Write::Filters))),
             (Scope::Write(None), Scope::Write(Some(Write::Follows))),
@@ -656,6 +906,24 @@ mod tests {
             (Scope::Write(None), Scope::Write(Some(Write::Statuses))),
             (Scope::Write(Some(Write::Statuses)), Scope::Follow),
             (Scope::Write(Some(Write::Follows)), Scope::Push),
+            (Scope::Push, Scope::AdminRead(None)),
+            (Scope::Follow, Scope::AdminRead(Some(AdminRead::Reports))),
+            (
+                Scope::AdminRead(None),
+                Scope::AdminRead(Some(AdminRead::Accounts)),
+            ),
+            (
+                Scope::AdminRead(Some(AdminRead::Accounts)),
+                Scope::AdminRead(Some(AdminRead::Reports)),
+            ),
+            (
+                Scope::AdminRead(Some(AdminRead::Reports)),
+                Scope::AdminWrite(None),
+            ),
+            (
+                Scope::AdminWrite(None),
+                Scope::AdminWrite(Some(AdminWrite::Accounts)),
+            ),
         ];
 
         for (a, b) in &tests {
@@ -669,6 +937,7 @@ mod tests {
             Scope::Read(None),
             Scope::Read(Some(Read::Accounts)),
             Scope::Read(Some(Read::Blocks)),
+            Scope::Read(Some(Read::Bookmarks)),
             Scope::Read(Some(Read::Favourites)),
             Scope::Read(Some(Read::Filters)),
             Scope::Read(Some(Read::Follows)),
@@ -681,6 +950,7 @@ mod tests {
             Scope::Write(None),
             Scope::Write(Some(Write::Accounts)),
             Scope::Write(Some(Write::Blocks)),
+            Scope::Write(Some(Write::Bookmarks)),
             Scope::Write(Some(Write::Favourites)),
             Scope::Write(Some(Write::Filters)),
             Scope::Write(Some(Write::Follows)),
@@ -692,12 +962,19 @@ mod tests {
             Scope::Write(Some(Write::Statuses)),
             Scope::Follow,
             Scope::Push,
+            Scope::AdminRead(None),
+            Scope::AdminRead(Some(AdminRead::Accounts)),
+            Scope::AdminRead(Some(AdminRead::Reports)),
+            Scope::AdminWrite(None),
+            Scope::AdminWrite(Some(AdminWrite::Accounts)),
+            Scope::AdminWrite(Some(AdminWrite::CanonicalEmailBlocks)),
         ];
 
         let expecteds = [
             "read".to_string(),
             "read:accounts".to_string(),
             "read:blocks".to_string(),
+            "read:bookmarks".to_string(),
             "read:favourites".to_string(),
             "read:filters".to_string(),
             "read:follows".to_string(),
@@ -710,6 +987,7 @@ mod tests {
             "write".to_string(),
             "write:accounts".to_string(),
             "write:blocks".to_string(),
+            "write:bookmarks".to_string(),
             "write:favourites".to_string(),
             "write:filters".to_string(),
             "write:follows".to_string(),
@@ -721,6 +999,12 @@ mod tests {
             "write:statuses".to_string(),
             "follow".to_string(),
             "push".to_string(),
+            "admin:read".to_string(),
+            "admin:read:accounts".to_string(),
+            "admin:read:reports".to_string(),
+            "admin:write".to_string(),
+            "admin:write:accounts".to_string(),
+            "admin:write:canonical_email_blocks".to_string(),
         ];
 
         let tests = values.iter().zip(expecteds.iter());
@@ -781,6 +1065,7 @@ mod tests {
             ("read", Scope::Read(None)),
             ("read:accounts", Scope::Read(Some(Read::Accounts))),
             ("read:blocks", Scope::Read(Some(Read::Blocks))),
+            ("read:bookmarks", Scope::Read(Some(Read::Bookmarks))),
             ("read:favourites", Scope::Read(Some(Read::Favourites))),
             ("read:filters", Scope::Read(Some(Read::Filters))),
             ("read:follows", Scope::Read(Some(Read::Follows))),
@@ -793,6 +1078,7 @@ mod tests {
             ("write", Scope::Write(None)),
             ("write:accounts", Scope::Write(Some(Write::Accounts))),
             ("write:blocks", Scope::Write(Some(Write::Blocks))),
+            ("write:bookmarks", Scope::Write(Some(Write::Bookmarks))),
             ("write:favourites", Scope::Write(Some(Write::Favourites))),
             ("write:filters", Scope::Write(Some(Write::Filters))),
             ("write:follows", Scope::Write(Some(Write::Follows))),
@@ -807,6 +1093,20 @@ mod tests {
             ("write:statuses", Scope::Write(Some(Write::Statuses))),
             ("follow", Scope::Follow),
             ("push", Scope::Push),
+            ("admin:read", Scope::AdminRead(None)),
+            (
+                "admin:read:accounts",
+                Scope::AdminRead(Some(AdminRead::Accounts)),
+            ),
+            (
+                "admin:read:ip_blocks",
+                Scope::AdminRead(Some(AdminRead::IpBlocks)),
+            ),
+            ("admin:write", Scope::AdminWrite(None)),
+            (
+                "admin:write:reports",
+                Scope::AdminWrite(Some(AdminWrite::Reports)),
+            ),
         ];
         for (source, expected) in &tests {
             let result = Scope::from_str(source).expect(&format!("Couldn't parse '{}'", &source));
@@ -814,6 +1114,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_admin_scopes_display() {
+        let scopes = Scopes::admin_write(AdminWrite::Accounts)
+            | Scopes::read_all()
+            | Scopes::admin_read_all();
+        assert_eq!(
+            &format!("{}", scopes),
+            "read admin:read admin:write:accounts"
+        );
+    }
+
     #[test]
     fn test_scopes_str_round_trip() {
         let original = "read write follow push";
@@ -1,5 +1,8 @@
+use crate::{entities::preferences::Preferences, MastodonClient};
+use chrono::prelude::*;
 use isolang::Language;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// A builder pattern struct for constructing a status.
 ///
@@ -29,6 +32,8 @@ pub struct StatusBuilder {
     content_type: Option<String>,
     visibility: Option<Visibility>,
     language: Option<Language>,
+    poll: Option<NewPoll>,
+    scheduled_at: Option<DateTime<Utc>>,
 }
 
 impl StatusBuilder {
@@ -46,6 +51,7 @@ impl StatusBuilder {
     /// #     client_secret: "".into(),
     /// #     redirect: "".into(),
     /// #     token: "".into(),
+    /// #     scopes: None,
     /// # };
     /// # let client = Mastodon::from(data);
     /// let status = StatusBuilder::new()
@@ -221,6 +227,128 @@ impl StatusBuilder {
         self
     }
 
+    /// Attach a poll to the post
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use elefren::prelude::*;
+    /// # use elefren::PollBuilder;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<(), elefren::Error> {
+    /// let poll = PollBuilder::new()
+    ///     .options(&["yes", "no"])
+    ///     .expires_in(Duration::from_secs(60 * 60 * 24))
+    ///     .build()?;
+    /// let status = StatusBuilder::new()
+    ///     .status("awoo?")
+    ///     .poll(poll)
+    ///     .build()?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn poll(&mut self, poll: NewPoll) -> &mut Self {
+        self.poll = Some(poll);
+        self
+    }
+
+    /// Schedule the post to be published at a later time
+    ///
+    /// The time must be at least 5 minutes in the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate chrono;
+    /// # use elefren::prelude::*;
+    /// # use chrono::{Duration, Utc};
+    /// # fn main() -> Result<(), elefren::Error> {
+    /// let status = StatusBuilder::new()
+    ///     .status("awoo, from the future")
+    ///     .scheduled_at(Utc::now() + Duration::days(1))
+    ///     .build()?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn scheduled_at(&mut self, scheduled_at: DateTime<Utc>) -> &mut Self {
+        self.scheduled_at = Some(scheduled_at);
+        self
+    }
+
+    /// Fill in the visibility, sensitive flag and language from the user's
+    /// preferences, for any of them that haven't been set yet
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use elefren::prelude::*;
+    /// # fn main() -> Result<(), elefren::Error> {
+    /// # let data = Data {
+    /// #     base: "".into(),
+    /// #     client_id: "".into(),
+    /// #     client_secret: "".into(),
+    /// #     redirect: "".into(),
+    /// #     token: "".into(),
+    /// #     scopes: None,
+    /// # };
+    /// # let client = Mastodon::from(data);
+    /// let preferences = client.get_preferences()?;
+    /// let status = StatusBuilder::new()
+    ///     .status("awoo, as usual")
+    ///     .preferences(&preferences)
+    ///     .build()?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn preferences(&mut self, preferences: &Preferences) -> &mut Self {
+        if self.visibility.is_none() {
+            self.visibility = Some(preferences.default_visibility);
+        }
+        if self.sensitive.is_none() {
+            self.sensitive = Some(preferences.default_sensitive);
+        }
+        if self.language.is_none() {
+            self.language = preferences
+                .default_language
+                .as_ref()
+                .and_then(|language| Language::from_639_1(language));
+        }
+        self
+    }
+
+    /// Constructs a NewStatus, using the user's preferences from the server
+    /// for the visibility, sensitive flag and language if they haven't been
+    /// set
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use elefren::prelude::*;
+    /// # fn main() -> Result<(), elefren::Error> {
+    /// # let data = Data {
+    /// #     base: "".into(),
+    /// #     client_id: "".into(),
+    /// #     client_secret: "".into(),
+    /// #     redirect: "".into(),
+    /// #     token: "".into(),
+    /// #     scopes: None,
+    /// # };
+    /// # let client = Mastodon::from(data);
+    /// let status = StatusBuilder::new()
+    ///     .status("awoo, as usual")
+    ///     .build_with_preferences(&client)?;
+    /// client.new_status(status)?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn build_with_preferences<C: MastodonClient + ?Sized>(
+        &self,
+        client: &C,
+    ) -> Result<NewStatus, crate::Error> {
+        let preferences = client.get_preferences()?;
+        self.clone().preferences(&preferences).build()
+    }
+
     /// Constructs a NewStatus
     ///
     /// # Example
@@ -238,6 +366,11 @@ impl StatusBuilder {
                 "status text or media ids are required in order to post a status".to_string(),
             ));
         }
+        if self.poll.is_some() && self.media_ids.is_some() {
+            return Err(crate::Error::Other(
+                "a status cannot have both a poll and media attachments".to_string(),
+            ));
+        }
         Ok(NewStatus {
             status: self.status.clone(),
             in_reply_to_id: self.in_reply_to_id.clone(),
@@ -247,6 +380,8 @@ impl StatusBuilder {
             visibility: self.visibility,
             language: self.language,
             content_type: self.content_type.clone(),
+            poll: self.poll.clone(),
+            scheduled_at: self.scheduled_at,
         })
     }
 }
@@ -270,6 +405,304 @@ pub struct NewStatus {
     language: Option<Language>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<NewPoll>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_at: Option<DateTime<Utc>>,
+}
+
+/// A builder pattern struct for constructing an edit to an existing status.
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// # use elefren::{EditStatusBuilder, MediaAttribute};
+///
+/// # fn main() -> Result<(), elefren::Error> {
+/// let edit = EditStatusBuilder::new()
+///     .status("a corrected status")
+///     .media_ids(&["foo"])
+///     .media_attribute(MediaAttribute::new("foo").description("a cat"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EditStatusBuilder {
+    status: Option<String>,
+    media_ids: Option<Vec<String>>,
+    media_attributes: Vec<MediaAttribute>,
+    sensitive: Option<bool>,
+    spoiler_text: Option<String>,
+    content_type: Option<String>,
+    language: Option<Language>,
+    poll: Option<NewPoll>,
+}
+
+impl EditStatusBuilder {
+    /// Create an EditStatusBuilder object
+    pub fn new() -> EditStatusBuilder {
+        EditStatusBuilder::default()
+    }
+
+    /// Set the new text for the post
+    pub fn status<I: Into<String>>(&mut self, status: I) -> &mut Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Set the media_ids for the post, replacing the existing attachments
+    pub fn media_ids<S: std::fmt::Display, I: IntoIterator<Item = S>>(
+        &mut self,
+        ids: I,
+    ) -> &mut Self {
+        self.media_ids = Some(ids.into_iter().map(|s| s.to_string()).collect::<Vec<_>>());
+        self
+    }
+
+    /// Update the description or focus of one of the attachments
+    pub fn media_attribute(&mut self, attribute: MediaAttribute) -> &mut Self {
+        self.media_attributes.push(attribute);
+        self
+    }
+
+    /// Set the sensitive attribute for the post
+    pub fn sensitive(&mut self, sensitive: bool) -> &mut Self {
+        self.sensitive = Some(sensitive);
+        self
+    }
+
+    /// Set the spoiler text/CW for the post
+    pub fn spoiler_text<I: Into<String>>(&mut self, spoiler_text: I) -> &mut Self {
+        self.spoiler_text = Some(spoiler_text.into());
+        self
+    }
+
+    /// Set the content type of the post
+    ///
+    /// This is a Pleroma and Glitch-soc extension of the API.
+    pub fn content_type<I: Into<String>>(&mut self, content_type: I) -> &mut Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set the language for the post
+    pub fn language(&mut self, language: Language) -> &mut Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Set the poll for the post
+    ///
+    /// Changing the options of a poll resets its votes.
+    pub fn poll(&mut self, poll: NewPoll) -> &mut Self {
+        self.poll = Some(poll);
+        self
+    }
+
+    /// Constructs an EditStatus
+    pub fn build(&self) -> Result<EditStatus, crate::Error> {
+        if self.status.is_none() && self.media_ids.is_none() {
+            return Err(crate::Error::Other(
+                "status text or media ids are required in order to edit a status".to_string(),
+            ));
+        }
+        if self.poll.is_some() && self.media_ids.is_some() {
+            return Err(crate::Error::Other(
+                "a status cannot have both a poll and media attachments".to_string(),
+            ));
+        }
+        Ok(EditStatus {
+            status: self.status.clone(),
+            media_ids: self.media_ids.clone(),
+            media_attributes: self.media_attributes.clone(),
+            sensitive: self.sensitive,
+            spoiler_text: self.spoiler_text.clone(),
+            content_type: self.content_type.clone(),
+            language: self.language,
+            poll: self.poll.clone(),
+        })
+    }
+}
+
+// Start an edit from a builder that was used to post the status, keeping the
+// fields that can be edited.
+impl From<&StatusBuilder> for EditStatusBuilder {
+    fn from(builder: &StatusBuilder) -> EditStatusBuilder {
+        EditStatusBuilder {
+            status: builder.status.clone(),
+            media_ids: builder.media_ids.clone(),
+            media_attributes: Vec::new(),
+            sensitive: builder.sensitive,
+            spoiler_text: builder.spoiler_text.clone(),
+            content_type: builder.content_type.clone(),
+            language: builder.language,
+            poll: builder.poll.clone(),
+        }
+    }
+}
+
+/// Represents an edit that can be sent to the PUT /api/v1/statuses/:id
+/// endpoint
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct EditStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    media_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    media_attributes: Vec<MediaAttribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sensitive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<Language>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<NewPoll>,
+}
+
+/// Updated attributes for a media attachment of an edited status
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MediaAttribute {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus: Option<String>,
+}
+
+impl MediaAttribute {
+    /// Create a new MediaAttribute for the attachment with the given id
+    pub fn new(id: &str) -> MediaAttribute {
+        MediaAttribute {
+            id: id.to_string(),
+            description: None,
+            focus: None,
+        }
+    }
+
+    /// Set the alt text description for the attachment
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Set the focus point for an image attachment
+    pub fn focus(mut self, f1: f32, f2: f32) -> Self {
+        self.focus = Some(format!("{},{}", f1, f2));
+        self
+    }
+}
+
+/// A builder pattern struct for constructing a poll to attach to a status.
+///
+/// # Example
+///
+/// ```
+/// # extern crate elefren;
+/// # use elefren::PollBuilder;
+/// # use std::time::Duration;
+///
+/// # fn main() -> Result<(), elefren::Error> {
+/// let poll = PollBuilder::new()
+///     .option("tea")
+///     .option("coffee")
+///     .expires_in(Duration::from_secs(3600))
+///     .multiple(true)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PollBuilder {
+    options: Vec<String>,
+    expires_in: Option<Duration>,
+    multiple: Option<bool>,
+    hide_totals: Option<bool>,
+}
+
+impl PollBuilder {
+    /// Create a PollBuilder object
+    pub fn new() -> PollBuilder {
+        PollBuilder::default()
+    }
+
+    /// Add a single choice to the poll
+    pub fn option<I: Into<String>>(&mut self, option: I) -> &mut Self {
+        self.options.push(option.into());
+        self
+    }
+
+    /// Add several choices to the poll
+    pub fn options<S: std::fmt::Display, I: IntoIterator<Item = S>>(
+        &mut self,
+        options: I,
+    ) -> &mut Self {
+        self.options
+            .extend(options.into_iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Set how long the poll should be open for
+    pub fn expires_in(&mut self, expires_in: Duration) -> &mut Self {
+        self.expires_in = Some(expires_in);
+        self
+    }
+
+    /// Set whether the poll allows multiple choices
+    pub fn multiple(&mut self, multiple: bool) -> &mut Self {
+        self.multiple = Some(multiple);
+        self
+    }
+
+    /// Set whether vote counts should be hidden until the poll ends
+    pub fn hide_totals(&mut self, hide_totals: bool) -> &mut Self {
+        self.hide_totals = Some(hide_totals);
+        self
+    }
+
+    /// Constructs a NewPoll
+    pub fn build(&self) -> Result<NewPoll, crate::Error> {
+        if self.options.len() < 2 {
+            return Err(crate::Error::Other(
+                "a poll needs at least two options".to_string(),
+            ));
+        }
+        if self.options.iter().any(|option| option.is_empty()) {
+            return Err(crate::Error::Other(
+                "poll options cannot be empty".to_string(),
+            ));
+        }
+        let expires_in = match self.expires_in {
+            Some(expires_in) if expires_in.as_secs() > 0 => expires_in.as_secs(),
+            _ => {
+                return Err(crate::Error::Other(
+                    "a poll needs a positive expires_in duration".to_string(),
+                ))
+            },
+        };
+        Ok(NewPoll {
+            options: self.options.clone(),
+            expires_in,
+            multiple: self.multiple,
+            hide_totals: self.hide_totals,
+        })
+    }
+}
+
+/// Represents a poll that can be attached to a new status
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct NewPoll {
+    options: Vec<String>,
+    expires_in: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multiple: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_totals: Option<bool>,
 }
 
 /// The visibility of a status.
@@ -313,6 +746,8 @@ mod tests {
             visibility: None,
             language: None,
             content_type: None,
+            poll: None,
+            scheduled_at: None,
         };
         assert_eq!(s, expected);
     }
@@ -364,4 +799,150 @@ mod tests {
             "{\"status\":\"a status\",\"language\":\"eng\"}"
         );
     }
+
+    #[test]
+    fn test_serialize_status_with_poll() {
+        let poll = PollBuilder::new()
+            .options(["yes", "no"])
+            .expires_in(Duration::from_secs(300))
+            .multiple(true)
+            .build()
+            .expect("Couldn't build poll");
+        let status = StatusBuilder::new()
+            .status("a question")
+            .poll(poll)
+            .build()
+            .expect("Couldn't build status");
+        assert_eq!(
+            serde_json::to_string(&status).expect("Couldn't serialize status"),
+            "{\"status\":\"a \
+             question\",\"poll\":{\"options\":[\"yes\",\"no\"],\"expires_in\":300,\"multiple\":\
+             true}}"
+        );
+    }
+
+    #[test]
+    fn test_poll_validation() {
+        assert!(PollBuilder::new()
+            .option("only one")
+            .expires_in(Duration::from_secs(300))
+            .build()
+            .is_err());
+        assert!(PollBuilder::new().options(["yes", "no"]).build().is_err());
+        assert!(PollBuilder::new()
+            .options(["yes", ""])
+            .expires_in(Duration::from_secs(300))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_poll_with_media_is_rejected() {
+        let poll = PollBuilder::new()
+            .options(["yes", "no"])
+            .expires_in(Duration::from_secs(300))
+            .build()
+            .expect("Couldn't build poll");
+        let status = StatusBuilder::new()
+            .status("a question")
+            .media_ids(["foo"])
+            .poll(poll)
+            .build();
+        assert!(status.is_err());
+    }
+
+    #[test]
+    fn test_serialize_scheduled_status() {
+        let scheduled_at: DateTime<Utc> = "2019-12-05T12:33:01Z"
+            .parse()
+            .expect("Couldn't parse timestamp");
+        let status = StatusBuilder::new()
+            .status("a status")
+            .scheduled_at(scheduled_at)
+            .build()
+            .expect("Couldn't build status");
+        assert_eq!(
+            serde_json::to_string(&status).expect("Couldn't serialize status"),
+            "{\"status\":\"a status\",\"scheduled_at\":\"2019-12-05T12:33:01Z\"}"
+        );
+    }
+
+    #[test]
+    fn test_serialize_edit_status() {
+        let edit = EditStatusBuilder::new()
+            .status("an edited status")
+            .spoiler_text("a CW")
+            .build()
+            .expect("Couldn't build edit");
+        assert_eq!(
+            serde_json::to_string(&edit).expect("Couldn't serialize edit"),
+            "{\"status\":\"an edited status\",\"spoiler_text\":\"a CW\"}"
+        );
+
+        let edit = EditStatusBuilder::new()
+            .media_ids(["foo"])
+            .media_attribute(
+                MediaAttribute::new("foo")
+                    .description("a cat")
+                    .focus(0.5, -1.0),
+            )
+            .build()
+            .expect("Couldn't build edit");
+        assert_eq!(
+            serde_json::to_string(&edit).expect("Couldn't serialize edit"),
+            "{\"media_ids\":[\"foo\"],\"media_attributes\":[{\"id\":\"foo\",\"description\":\"a \
+             cat\",\"focus\":\"0.5,-1\"}]}"
+        );
+    }
+
+    #[test]
+    fn test_edit_from_status_builder() {
+        let mut builder = StatusBuilder::new();
+        builder
+            .status("a status")
+            .visibility(Visibility::Private)
+            .sensitive(true);
+        let edit = EditStatusBuilder::from(&builder)
+            .status("an edited status")
+            .build()
+            .expect("Couldn't build edit");
+        let expected = EditStatus {
+            status: Some("an edited status".to_string()),
+            sensitive: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(edit, expected);
+    }
+
+    #[test]
+    fn test_empty_edit_is_rejected() {
+        assert!(EditStatusBuilder::new().sensitive(true).build().is_err());
+    }
+
+    #[test]
+    fn test_preferences() {
+        use crate::entities::preferences::ExpandMedia;
+
+        let preferences = Preferences {
+            default_visibility: Visibility::Unlisted,
+            default_sensitive: true,
+            default_language: Some("de".to_string()),
+            expand_media: ExpandMedia::Default,
+            expand_spoilers: false,
+        };
+        let status = StatusBuilder::new()
+            .status("a status")
+            .visibility(Visibility::Direct)
+            .preferences(&preferences)
+            .build()
+            .expect("Couldn't build status");
+        let expected = NewStatus {
+            status: Some("a status".to_string()),
+            sensitive: Some(true),
+            visibility: Some(Visibility::Direct),
+            language: Some(Language::Deu),
+            ..Default::default()
+        };
+        assert_eq!(status, expected);
+    }
 }